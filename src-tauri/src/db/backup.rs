@@ -0,0 +1,372 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration as ChronoDuration, NaiveDateTime, Utc};
+use rusqlite::{Connection, DatabaseName};
+use tauri::{AppHandle, Manager};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use super::commands::DbState;
+use super::crypto;
+use super::migration::{latest_schema_version, schema_version};
+use super::models::BackupInfo;
+use crate::error::{AppError, AppResult};
+use crate::settings;
+
+/// 백업 파일명의 시각 형식 (UTC)
+const FILE_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// 예약 백업 확인 주기
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// 백업 종류 (파일명 끝에 기록)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackupReason {
+    /// 스키마 변경 직전
+    PreMigration,
+    /// 주기적인 예약 백업
+    Scheduled,
+    /// 백업 복원 직전의 현재 DB
+    PreRestore,
+    /// DB 점검 복구 직전
+    PreRepair,
+}
+
+impl BackupReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PreMigration => "pre-migration",
+            Self::Scheduled => "scheduled",
+            Self::PreRestore => "pre-restore",
+            Self::PreRepair => "pre-repair",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pre-migration" => Some(Self::PreMigration),
+            "scheduled" => Some(Self::Scheduled),
+            "pre-restore" => Some(Self::PreRestore),
+            "pre-repair" => Some(Self::PreRepair),
+            _ => None,
+        }
+    }
+}
+
+/// 백업 설정 (tbl_setting)
+struct BackupPolicy {
+    /// 예약 백업 주기 (None이면 예약 백업 안 함)
+    interval: Option<ChronoDuration>,
+    /// 종류별 보관 개수
+    keep_count: usize,
+}
+
+impl BackupPolicy {
+    // 설정 테이블이 아직 없는 DB(마이그레이션 전)는 기본값 사용
+    fn load(conn: &Connection) -> Self {
+        let interval_hours = settings::get_int(conn, "backup_interval_hours");
+        let keep_count = settings::get_int(conn, "backup_keep_count").max(1) as usize;
+
+        Self {
+            interval: (interval_hours > 0).then(|| ChronoDuration::hours(interval_hours)),
+            keep_count,
+        }
+    }
+}
+
+/// 백업 디렉토리 ({app_data_dir}/storage/backups)
+///
+/// DB별 백업은 이 아래의 DB별 하위 디렉토리(`db_backup_dir`)에 저장된다.
+pub fn backup_dir(app_handle: &AppHandle) -> AppResult<PathBuf> {
+    let mut dir = app_handle.path().app_data_dir()?;
+    dir.push("storage");
+    dir.push("backups");
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn db_stem(db_path: &Path) -> String {
+    db_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "mirumi".to_string())
+}
+
+/// DB별 백업 디렉토리 (`{backups}/{원본파일명}-{경로 해시}`)
+///
+/// 파일명이 같은 다른 폴더의 DB와 백업이 섞이지 않도록 DB의 전체 경로로 구분한다.
+fn db_backup_dir(dir: &Path, db_path: &Path) -> PathBuf {
+    // 아직 파일이 없으면 정규화할 수 없으므로 주어진 경로 사용
+    let path = fs::canonicalize(db_path).unwrap_or_else(|_| db_path.to_path_buf());
+    dir.join(format!("{}-{:016x}", db_stem(db_path), path_hash(&path)))
+}
+
+/// 경로 해시 (FNV-1a, 앱 버전이 바뀌어도 같은 값이어야 하므로 std 해시 사용 안 함)
+fn path_hash(path: &Path) -> u64 {
+    path.to_string_lossy()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+/// 백업 생성 (SQLite online backup API로 열린 연결에서 일관된 스냅샷 복사)
+///
+/// 경로: `{dir}/{원본파일명}-{경로 해시}/{원본파일명}.backup-{YYYYMMDD}-{HHmmss}-{종류}.db`
+pub fn create_backup(
+    conn: &Connection,
+    db_path: &Path,
+    dir: &Path,
+    reason: BackupReason,
+) -> AppResult<BackupInfo> {
+    let dir = db_backup_dir(dir, db_path);
+    fs::create_dir_all(&dir)?;
+    let file_name = format!(
+        "{}.backup-{}-{}.db",
+        db_stem(db_path),
+        Utc::now().format(FILE_TIMESTAMP_FORMAT),
+        reason.as_str()
+    );
+    let path = dir.join(&file_name);
+
+    // 복사가 끝난 뒤 이름을 바꿔 목록에 미완성 파일이 보이지 않도록 함
+    let temp_path = dir.join(format!("{}.tmp", file_name));
+    if temp_path.exists() {
+        fs::remove_file(&temp_path)?;
+    }
+    // 암호화된 DB는 같은 암호로 암호화된 백업을 만듦 (online backup API 사용 불가)
+    if crypto::is_encrypted(db_path) {
+        crypto::export(conn, &temp_path, None)?;
+    } else {
+        conn.backup(DatabaseName::Main, &temp_path, None)?;
+    }
+    fs::rename(&temp_path, &path)?;
+
+    log::info!("[Backup] Created {} ({})", file_name, reason.as_str());
+
+    parse_backup(&path, &db_stem(db_path))
+        .ok_or_else(|| AppError::Internal(format!("백업 파일을 확인할 수 없습니다: {}", file_name)))
+}
+
+/// 백업 파일 정보 (이 DB의 백업 파일명이 아니면 None)
+fn parse_backup(path: &Path, stem: &str) -> Option<BackupInfo> {
+    let file_name = path.file_name()?.to_str()?;
+    let rest = file_name
+        .strip_prefix(stem)?
+        .strip_prefix(".backup-")?
+        .strip_suffix(".db")?;
+    let (timestamp, reason) = (rest.get(..15)?, rest.get(15..)?.strip_prefix('-')?);
+    let reason = BackupReason::parse(reason)?;
+    let created_at = NaiveDateTime::parse_from_str(timestamp, FILE_TIMESTAMP_FORMAT).ok()?;
+    let size_bytes = fs::metadata(path).ok()?.len();
+
+    Some(BackupInfo {
+        file_name: file_name.to_string(),
+        path: path.to_string_lossy().to_string(),
+        reason: reason.as_str().to_string(),
+        created_at: created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        size_bytes,
+    })
+}
+
+/// DB의 백업 목록 (최신순)
+pub fn list_backups(dir: &Path, db_path: &Path) -> AppResult<Vec<BackupInfo>> {
+    let dir = db_backup_dir(dir, db_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let stem = db_stem(db_path);
+    let mut backups: Vec<BackupInfo> = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| parse_backup(&entry.path(), &stem))
+        .collect();
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+/// 종류별로 최근 `keep_count`개만 남기고 삭제
+fn prune_backups(dir: &Path, db_path: &Path, keep_count: usize) -> AppResult<()> {
+    let backups = list_backups(dir, db_path)?;
+    for reason in [
+        BackupReason::PreMigration,
+        BackupReason::Scheduled,
+        BackupReason::PreRestore,
+        BackupReason::PreRepair,
+    ] {
+        for backup in backups
+            .iter()
+            .filter(|b| b.reason == reason.as_str())
+            .skip(keep_count)
+        {
+            match fs::remove_file(&backup.path) {
+                Ok(()) => log::info!("[Backup] Removed old backup {}", backup.file_name),
+                Err(e) => log::warn!("[Backup] Failed to remove {}: {}", backup.file_name, e),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 백업 생성 후 보관 개수 정리
+pub fn backup_and_prune(
+    conn: &Connection,
+    db_path: &Path,
+    dir: &Path,
+    reason: BackupReason,
+) -> AppResult<BackupInfo> {
+    let policy = BackupPolicy::load(conn);
+    let backup = create_backup(conn, db_path, dir, reason)?;
+    prune_backups(dir, db_path, policy.keep_count)?;
+    Ok(backup)
+}
+
+/// 적용할 마이그레이션이 있으면 먼저 백업
+///
+/// 테이블이 없는 새 DB와 앱보다 최신 스키마의 DB(마이그레이션하지 않음)는 건너뛴다.
+pub fn backup_before_migration(conn: &Connection, db_path: &Path, dir: &Path) -> AppResult<()> {
+    let version = schema_version(conn)?;
+    if version >= latest_schema_version() {
+        return Ok(());
+    }
+
+    let table_count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'",
+        [],
+        |row| row.get(0),
+    )?;
+    if table_count == 0 {
+        return Ok(());
+    }
+
+    backup_and_prune(conn, db_path, dir, BackupReason::PreMigration)?;
+    Ok(())
+}
+
+/// 예약 백업 루프
+///
+/// 마지막 백업(종류 무관)이 설정한 주기보다 오래되었으면 백업한다.
+/// 마이그레이션을 마친 DB만 대상으로 하도록 `get_db_status` 등으로 열린 경로만 사용한다.
+pub async fn run_backup_schedule(app_handle: AppHandle) {
+    let mut ticker = interval(SCHEDULE_CHECK_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = scheduled_backup(&app_handle).await {
+            log::warn!("[Backup] Scheduled backup failed: {}", e);
+        }
+    }
+}
+
+async fn scheduled_backup(app_handle: &AppHandle) -> AppResult<()> {
+    let state = app_handle.state::<DbState>();
+    let Some(db_path) = state.db_path.lock()?.clone() else {
+        return Ok(());
+    };
+    // 읽기 전용 DB는 쓰기 중인 쪽(다른 인스턴스)이 백업하거나 백업 대상이 아님
+    // 암호 입력 전인 암호화 DB는 열 수 없으므로 잠금 해제 후 백업
+    if !db_path.exists()
+        || state.worker.is_read_only(&db_path)
+        || state.worker.needs_passphrase(&db_path)
+    {
+        return Ok(());
+    }
+
+    let dir = backup_dir(app_handle)?;
+    state
+        .worker
+        .run(db_path.clone(), move |conn| {
+            let Some(interval) = BackupPolicy::load(conn).interval else {
+                return Ok(());
+            };
+
+            let last_backup_at = list_backups(&dir, &db_path)?.first().and_then(|b| {
+                NaiveDateTime::parse_from_str(&b.created_at, "%Y-%m-%d %H:%M:%S").ok()
+            });
+            let due = match last_backup_at {
+                Some(at) => Utc::now().naive_utc() - at >= interval,
+                None => true,
+            };
+            if due {
+                backup_and_prune(conn, &db_path, &dir, BackupReason::Scheduled)?;
+            }
+            Ok(())
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 테스트용 임시 디렉토리 (테스트가 끝나면 삭제)
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let dir = std::env::temp_dir().join(format!("mirumi-test-{}", uuid::Uuid::new_v4()));
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn parse_backup_round_trip() {
+        let temp = TempDir::new();
+        let path = temp.0.join("mirumi.backup-20251216-143052-scheduled.db");
+        fs::write(&path, b"backup").unwrap();
+
+        let backup = parse_backup(&path, "mirumi").unwrap();
+        assert_eq!(
+            backup.file_name,
+            "mirumi.backup-20251216-143052-scheduled.db"
+        );
+        assert_eq!(backup.reason, "scheduled");
+        assert_eq!(backup.created_at, "2025-12-16 14:30:52");
+        assert_eq!(backup.size_bytes, 6);
+    }
+
+    #[test]
+    fn parse_backup_rejects_other_files() {
+        let temp = TempDir::new();
+        for name in [
+            "other.backup-20251216-143052-scheduled.db",
+            "mirumi.backup-20251216-143052-unknown.db",
+            "mirumi.backup-2025121-143052-scheduled.db",
+            "mirumi.backup-20251216-143052-scheduled.db.tmp",
+            "mirumi.db",
+        ] {
+            let path = temp.0.join(name);
+            fs::write(&path, b"backup").unwrap();
+            assert!(parse_backup(&path, "mirumi").is_none(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn same_stem_databases_keep_separate_backups() {
+        let temp = TempDir::new();
+        let backups = temp.0.join("backups");
+        let first = temp.0.join("a").join("mirumi.db");
+        let second = temp.0.join("b").join("mirumi.db");
+
+        for db_path in [&first, &second] {
+            fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+            let conn = Connection::open(db_path).unwrap();
+            conn.execute_batch("CREATE TABLE t (id INTEGER);").unwrap();
+        }
+
+        let conn = Connection::open(&first).unwrap();
+        let created = create_backup(&conn, &first, &backups, BackupReason::Scheduled).unwrap();
+
+        let first_backups = list_backups(&backups, &first).unwrap();
+        assert_eq!(first_backups.len(), 1);
+        assert_eq!(first_backups[0].file_name, created.file_name);
+        assert!(list_backups(&backups, &second).unwrap().is_empty());
+    }
+}
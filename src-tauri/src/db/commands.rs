@@ -4,21 +4,74 @@ use std::sync::Mutex;
 
 use rusqlite::Connection;
 use serde_json::json;
-use tauri::{AppHandle, Manager, State};
-
-use super::migration::{get_table_list, run_migrations};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::backup::{self, BackupReason};
+use super::crypto;
+use super::integrity;
+use super::lock;
+use super::maintenance;
+use super::migration::{check_readable_schema, get_table_list, has_column, run_migrations};
 use super::models::*;
+use super::timer_store;
+use super::worker::DbWorker;
+use super::workspace;
+use crate::error::{AppError, AppResult};
+use crate::settings;
 
 /// DB 연결 상태
 pub struct DbState {
     pub db_path: Mutex<Option<PathBuf>>,
+    /// 실행 중 타이머 복구를 마친 DB 경로 (프로세스당 DB별 1회만 복구)
+    pub recovered_path: Mutex<Option<PathBuf>>,
+    /// 이 인스턴스가 잠금을 보유한 DB 경로 (None이면 읽기 전용이거나 미설정)
+    pub locked_path: Mutex<Option<PathBuf>>,
+    /// 사용자가 읽기 전용으로 연 DB 경로 (백업/내보낸 DB 열람, 잠금/마이그레이션/복구 안 함)
+    pub read_only_opened: Mutex<Option<PathBuf>>,
+    /// 연결을 유지하는 DB 작업 스레드
+    pub worker: DbWorker,
 }
 
 impl Default for DbState {
     fn default() -> Self {
         Self {
             db_path: Mutex::new(None),
+            recovered_path: Mutex::new(None),
+            locked_path: Mutex::new(None),
+            read_only_opened: Mutex::new(None),
+            worker: DbWorker::spawn(),
+        }
+    }
+}
+
+impl DbState {
+    /// 현재 DB에서 작업 실행 (작업 스레드에서 실행되므로 메인 스레드를 막지 않음)
+    pub async fn run<T, F>(&self, app_handle: &AppHandle, f: F) -> AppResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+    {
+        let path = current_db_path(app_handle, self)?;
+        self.worker.run(path, f).await
+    }
+
+    /// 현재 DB를 수정하는 작업 실행 (읽기 전용으로 열린 DB면 `DB_READ_ONLY`)
+    pub async fn run_mut<T, F>(&self, app_handle: &AppHandle, f: F) -> AppResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+    {
+        let path = current_db_path(app_handle, self)?;
+        self.check_writable(&path)?;
+        self.worker.run(path, f).await
+    }
+
+    /// 읽기 전용으로 열린 DB면 `DB_READ_ONLY`
+    pub fn check_writable(&self, path: &Path) -> AppResult<()> {
+        if self.worker.is_read_only(path) {
+            return Err(AppError::ReadOnly);
         }
+        Ok(())
     }
 }
 
@@ -27,78 +80,93 @@ impl Default for DbState {
 // ============================================================================
 
 /// config.json 파일 경로
-fn config_file(app_handle: &AppHandle) -> Result<PathBuf, String> {
+fn config_file(app_handle: &AppHandle) -> AppResult<PathBuf> {
     let mut dir = app_handle
         .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?;
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        .app_data_dir()?;
+    fs::create_dir_all(&dir)?;
     dir.push("config.json");
     Ok(dir)
 }
 
-/// config.json에서 DB 경로 로드
-fn load_config_path(app_handle: &AppHandle) -> Result<Option<PathBuf>, String> {
+/// config.json 로드 (이전 버전의 db_path는 워크스페이스로 옮겨 저장)
+pub(crate) fn load_config(app_handle: &AppHandle) -> AppResult<AppConfig> {
     let file = config_file(app_handle)?;
     if !file.exists() {
-        return Ok(None);
+        return Ok(AppConfig::default());
+    }
+    let data = fs::read_to_string(&file)?;
+    let mut config: AppConfig = serde_json::from_str(&data).unwrap_or_default();
+    let migrated_settings = settings::migrate_legacy_config(&mut config);
+    if workspace::migrate_legacy_path(&mut config) || migrated_settings {
+        save_config(app_handle, &config)?;
     }
-    let data = fs::read_to_string(&file).map_err(|e| e.to_string())?;
-    let config: AppConfig = serde_json::from_str(&data).unwrap_or_default();
-    Ok(config.db_path.map(PathBuf::from))
+    Ok(config)
 }
 
-/// config.json에 DB 경로 저장
-fn save_config_path(app_handle: &AppHandle, path: &Path) -> Result<(), String> {
+/// config.json 저장
+pub(crate) fn save_config(app_handle: &AppHandle, config: &AppConfig) -> AppResult<()> {
     let file = config_file(app_handle)?;
-    
-    // 기존 설정 로드
-    let mut config: AppConfig = if file.exists() {
-        let data = fs::read_to_string(&file).map_err(|e| e.to_string())?;
-        serde_json::from_str(&data).unwrap_or_default()
-    } else {
-        AppConfig::default()
-    };
-    
-    config.db_path = Some(path.to_string_lossy().to_string());
-    
-    let serialized = serde_json::to_vec_pretty(&config).map_err(|e| e.to_string())?;
-    fs::write(&file, serialized).map_err(|e| e.to_string())
+    let serialized = serde_json::to_vec_pretty(config)?;
+    fs::write(&file, serialized).map_err(AppError::from)
+}
+
+/// config.json에서 DB 경로 로드
+fn load_config_path(app_handle: &AppHandle) -> AppResult<Option<PathBuf>> {
+    Ok(load_config(app_handle)?.db_path.map(PathBuf::from))
+}
+
+/// config.json에 DB 경로 저장 (해당 경로의 워크스페이스를 활성화)
+fn save_config_path(app_handle: &AppHandle, path: &Path) -> AppResult<()> {
+    let mut config = load_config(app_handle)?;
+    workspace::activate_path(&mut config, path);
+    save_config(app_handle, &config)
 }
 
 /// 기본 DB 경로
-fn default_db_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+fn default_db_path(app_handle: &AppHandle) -> AppResult<PathBuf> {
     let mut dir = app_handle
         .path()
-        .app_data_dir()
-        .map_err(|e| e.to_string())?;
+        .app_data_dir()?;
     dir.push("storage");
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir)?;
     Ok(dir.join("mirumi.db"))
 }
 
-/// DB 연결 획득
-fn get_connection(app_handle: &AppHandle, state: &State<DbState>) -> Result<Connection, String> {
+/// 현재 DB 경로 (설정되지 않았거나 파일이 없으면 오류)
+fn current_db_path(app_handle: &AppHandle, state: &DbState) -> AppResult<PathBuf> {
     let path = state
         .db_path
-        .lock()
-        .map_err(|e| e.to_string())?
+        .lock()?
         .clone()
         .or_else(|| load_config_path(app_handle).ok().flatten())
-        .ok_or_else(|| "DB가 설정되지 않았습니다.".to_string())?;
+        .ok_or(AppError::DbNotConfigured)?;
 
     if !path.exists() {
-        return Err("DB 파일이 존재하지 않습니다.".to_string());
+        return Err(AppError::db_file_not_found(&path));
     }
 
-    let conn = Connection::open(&path).map_err(|e| e.to_string())?;
-    conn.execute("PRAGMA foreign_keys = ON", [])
-        .map_err(|e| e.to_string())?;
-    Ok(conn)
+    Ok(path)
+}
+
+/// 현재 DB에 쓸 수 있는지 확인 (커맨드 외부, 예: 타이머 시작 전)
+/// DB가 설정되지 않은 경우는 각 작업에서 처리하므로 통과
+pub fn ensure_writable(app_handle: &AppHandle) -> AppResult<()> {
+    let state = app_handle.state::<DbState>();
+    let path = state.db_path.lock()?.clone();
+    match path {
+        Some(path) => state.check_writable(&path),
+        None => Ok(()),
+    }
 }
 
 /// DB 상태 빌드
-fn build_status(path: &Path, configured: bool) -> Result<DbStatus, String> {
+async fn build_status(
+    app_handle: &AppHandle,
+    state: &DbState,
+    path: &Path,
+    configured: bool,
+) -> AppResult<DbStatus> {
     let exists = path.exists();
     let size_bytes = if exists {
         fs::metadata(path).ok().map(|m| m.len())
@@ -106,19 +174,45 @@ fn build_status(path: &Path, configured: bool) -> Result<DbStatus, String> {
         None
     };
 
-    let tables = if exists {
-        let conn = Connection::open(path).map_err(|e| e.to_string())?;
-        get_table_list(&conn)?
+    let encrypted = crypto::is_encrypted(path);
+    let needs_passphrase = state.worker.needs_passphrase(path);
+
+    let (tables, stats) = if exists && !needs_passphrase {
+        let db_path = path.to_path_buf();
+        let (tables, stats) = state
+            .worker
+            .run(path.to_path_buf(), move |conn| {
+                Ok((get_table_list(conn)?, maintenance::collect_stats(conn, &db_path)?))
+            })
+            .await?;
+        (tables, Some(stats))
     } else {
-        vec![]
+        (vec![], None)
     };
 
+    // 백업 목록을 읽지 못해도 상태 조회는 실패하지 않음
+    let last_backup_at = backup::backup_dir(app_handle)
+        .and_then(|dir| backup::list_backups(&dir, path))
+        .ok()
+        .and_then(|backups| backups.into_iter().next())
+        .map(|b| b.created_at);
+
+    let lock_holder = lock::holder(path);
+    let lock_stale = lock_holder.as_ref().is_some_and(|(_, stale)| *stale);
+
     Ok(DbStatus {
         configured,
         path: path.to_string_lossy().to_string(),
         exists,
         size_bytes,
         tables,
+        stats,
+        last_backup_at,
+        read_only: state.worker.is_read_only(path),
+        lock_holder: lock_holder.map(|(info, _)| info),
+        lock_stale,
+        encrypted,
+        needs_passphrase,
     })
 }
 
@@ -128,33 +222,36 @@ fn build_status(path: &Path, configured: bool) -> Result<DbStatus, String> {
 
 /// DB 상태 조회
 #[tauri::command]
-pub fn get_db_status(app_handle: AppHandle, state: State<DbState>) -> Result<DbStatus, String> {
+pub async fn get_db_status(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+) -> AppResult<DbStatus> {
     // 먼저 state에서 확인
-    let state_path = state.db_path.lock().map_err(|e| e.to_string())?.clone();
+    let state_path = state.db_path.lock()?.clone();
     
     if let Some(path) = state_path {
-        // 앱 재시작 시에도 마이그레이션 실행
-        if path.exists() {
-            let conn = Connection::open(&path).map_err(|e| e.to_string())?;
-            run_migrations(&conn)?;
-            reset_all_tasks_to_paused(&conn)?;
+        // 앱 재시작 시에도 마이그레이션 실행 (사용자가 읽기 전용으로 연 DB, 암호 입력 전인 DB 제외)
+        let opened_read_only = state.read_only_opened.lock()?.as_deref() == Some(path.as_path());
+        if path.exists() && !opened_read_only && !state.worker.needs_passphrase(&path) {
+            prepare_db(&app_handle, &state, &path).await?;
         }
-        return build_status(&path, true);
+        crate::tray::refresh_menu(&app_handle);
+        return build_status(&app_handle, &state, &path, true).await;
     }
 
     // config.json에서 확인
     if let Some(path) = load_config_path(&app_handle)? {
         // state 업데이트
-        *state.db_path.lock().map_err(|e| e.to_string())? = Some(path.clone());
+        *state.db_path.lock()? = Some(path.clone());
         
-        // 마이그레이션 실행 (기존 DB에 새 컬럼 추가)
-        if path.exists() {
-            let conn = Connection::open(&path).map_err(|e| e.to_string())?;
-            run_migrations(&conn)?;
-            reset_all_tasks_to_paused(&conn)?;
+        // 마이그레이션 실행 (기존 DB에 새 컬럼 추가, 암호화된 DB는 암호 입력 후)
+        if path.exists() && !state.worker.needs_passphrase(&path) {
+            prepare_db(&app_handle, &state, &path).await?;
         }
+        // 앱 시작 시 빈 트레이 메뉴를 DB 내용으로 채움
+        crate::tray::refresh_menu(&app_handle);
         
-        return build_status(&path, true);
+        return build_status(&app_handle, &state, &path, true).await;
     }
 
     // 미설정 상태
@@ -162,343 +259,920 @@ pub fn get_db_status(app_handle: AppHandle, state: State<DbState>) -> Result<DbS
     Ok(DbStatus {
         configured: false,
         path: default_path.to_string_lossy().to_string(),
-        exists: false,
-        size_bytes: None,
-        tables: vec![],
+        ..Default::default()
     })
 }
 
-/// 새 DB 초기화
+/// 새 DB 초기화 (passphrase가 있으면 암호화된 DB로 생성)
 #[tauri::command]
-pub fn init_db(
+pub async fn init_db(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     path: Option<String>,
-) -> Result<DbStatus, String> {
-    let db_path = path
-        .map(PathBuf::from)
-        .unwrap_or_else(|| default_db_path(&app_handle).unwrap());
+    passphrase: Option<String>,
+) -> AppResult<DbStatus> {
+    let db_path = match path {
+        Some(path) => PathBuf::from(path),
+        None => default_db_path(&app_handle)?,
+    };
 
     // 부모 디렉토리 생성
     if let Some(parent) = db_path.parent() {
-        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        fs::create_dir_all(parent)?;
     }
 
     // DB 생성 및 마이그레이션
-    let conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
-    run_migrations(&conn)?;
-    reset_all_tasks_to_paused(&conn)?;
-
-    // 설정 저장
-    save_config_path(&app_handle, &db_path)?;
-    *state.db_path.lock().map_err(|e| e.to_string())? = Some(db_path.clone());
-
-    build_status(&db_path, true)
+    unlock(&state, &db_path, passphrase)?;
+    open_db(&app_handle, &state, &db_path, false).await
 }
 
 /// 기존 DB 불러오기
+///
+/// 다른 인스턴스가 사용 중이면 `DB_LOCKED`, 응답 없는 잠금이면 `DB_LOCK_STALE`을 반환한다.
+/// 사용자가 확인하면 `take_over`로 응답 없는 잠금을 가져온다.
+/// `read_only`면 파일을 바꾸지 않고 열람만 한다 (백업, 다른 사람이 내보낸 DB 등).
+/// 암호화된 DB는 `passphrase`로 잠금을 해제한다 (없으면 `PASSPHRASE_REQUIRED`).
 #[tauri::command]
-pub fn load_existing_db(
+pub async fn load_existing_db(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     path: String,
-) -> Result<DbStatus, String> {
+    take_over: Option<bool>,
+    read_only: Option<bool>,
+    passphrase: Option<String>,
+) -> AppResult<DbStatus> {
     let db_path = PathBuf::from(&path);
 
     if !db_path.exists() {
-        return Err("파일이 존재하지 않습니다.".to_string());
+        return Err(AppError::db_file_not_found(&db_path));
+    }
+    unlock(&state, &db_path, passphrase)?;
+    if read_only.unwrap_or(false) {
+        return open_db_read_only(&app_handle, &state, &db_path).await;
     }
 
     // DB 연결 테스트 및 마이그레이션
-    let conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
-    run_migrations(&conn)?;
-    reset_all_tasks_to_paused(&conn)?;
+    open_db(&app_handle, &state, &db_path, take_over.unwrap_or(false)).await
+}
+
+/// DB 열기 (마이그레이션, 타이머 복구 후 활성 워크스페이스로 저장)
+///
+/// 다른 DB에서 전환하는 경우 실행 중인 타이머는 이전 DB에 저장한 뒤 정리한다.
+/// 저장된 타이머는 해당 DB로 돌아오면 다시 복구된다.
+/// 다른 인스턴스가 사용 중인 DB는 잠금을 얻지 못하면 열지 않는다.
+async fn open_db(
+    app_handle: &AppHandle,
+    state: &DbState,
+    db_path: &Path,
+    take_over: bool,
+) -> AppResult<DbStatus> {
+    let previous = state.db_path.lock()?.clone();
+    let switching = previous.as_deref() != Some(db_path);
+
+    lock::acquire(db_path, take_over)?;
+    state.worker.set_read_only_path(None);
+    *state.read_only_opened.lock()? = None;
+    if let Err(e) = migrate(app_handle, state, db_path).await {
+        if switching {
+            lock::release(db_path);
+        }
+        return Err(e);
+    }
+
+    // 이전 DB 경로가 바뀌기 전에 저장해야 이전 DB에 기록됨 (이전 DB가 없으면 기록 없이 정리)
+    if switching {
+        crate::timer::suspend_timers(app_handle).await;
+    }
+    recover_running_tasks(app_handle, state, db_path).await?;
 
     // 설정 저장
-    save_config_path(&app_handle, &db_path)?;
-    *state.db_path.lock().map_err(|e| e.to_string())? = Some(db_path.clone());
+    save_config_path(app_handle, db_path)?;
+    *state.db_path.lock()? = Some(db_path.to_path_buf());
+    let previous_lock = state.locked_path.lock()?.replace(db_path.to_path_buf());
+    if let Some(previous_lock) = previous_lock.filter(|p| p != db_path) {
+        lock::release(&previous_lock);
+    }
+    crate::tray::refresh_menu(app_handle);
+
+    build_status(app_handle, state, db_path, true).await
+}
+
+/// DB를 읽기 전용으로 열기 (SQLITE_OPEN_READ_ONLY)
+///
+/// 잠금, 마이그레이션, 타이머 복구를 하지 않고 config.json도 바꾸지 않는다.
+/// 앱을 다시 시작하면 활성 워크스페이스의 DB가 열린다.
+async fn open_db_read_only(
+    app_handle: &AppHandle,
+    state: &DbState,
+    db_path: &Path,
+) -> AppResult<DbStatus> {
+    // 마이그레이션하지 않으므로 읽을 수 없는 스키마면 현재 DB를 그대로 두고 거부
+    // (암호 입력 전인 DB는 잠금 해제 후 읽을 때 확인)
+    state.worker.set_read_only_path(Some(db_path.to_path_buf()));
+    if !state.worker.needs_passphrase(db_path) {
+        if let Err(e) = state
+            .worker
+            .run(db_path.to_path_buf(), |conn| check_readable_schema(conn))
+            .await
+        {
+            state.worker.set_read_only_path(state.read_only_opened.lock()?.clone());
+            return Err(e);
+        }
+    }
+
+    // 이전 DB 경로가 바뀌기 전에 저장해야 이전 DB에 기록됨 (이전 DB가 없으면 기록 없이 정리)
+    let previous = state.db_path.lock()?.clone();
+    if previous.as_deref() != Some(db_path) {
+        crate::timer::suspend_timers(app_handle).await;
+    }
 
-    build_status(&db_path, true)
+    *state.read_only_opened.lock()? = Some(db_path.to_path_buf());
+    *state.db_path.lock()? = Some(db_path.to_path_buf());
+    if let Some(previous_lock) = state.locked_path.lock()?.take() {
+        lock::release(&previous_lock);
+    }
+    log::info!("[DB] Opened read-only: {}", db_path.display());
+
+    crate::tray::refresh_menu(app_handle);
+    build_status(app_handle, state, db_path, true).await
+}
+
+/// DB 파일 이동 (복사 → 검증 → 설정 저장 → 이전 파일 삭제)
+///
+/// 복사본 검증이나 설정 저장에 실패하면 복사본을 지우고 기존 DB를 그대로 사용한다.
+/// 실행 중인 타이머는 복사 전에 저장해 두었다가 이동한 DB(실패하면 기존 DB)에서 이어서 실행한다.
+#[tauri::command]
+pub async fn move_db(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    path: String,
+) -> AppResult<DbStatus> {
+    let old_path = current_db_path(&app_handle, &state)?;
+    let new_path = PathBuf::from(&path);
+
+    if new_path == old_path {
+        return Err(AppError::InvalidInput("현재 DB와 같은 경로입니다.".to_string()));
+    }
+    if new_path.exists() {
+        return Err(AppError::InvalidInput("대상 경로에 이미 파일이 있습니다.".to_string()));
+    }
+    state.check_writable(&old_path)?;
+
+    // 실행 중인 타이머를 이전 DB에 저장하고 정리한 뒤 복사 (복사 중 실행 기록이 바뀌지 않도록)
+    crate::timer::suspend_timers(&app_handle).await;
+    if let Err(e) = copy_db(&app_handle, &state, &old_path, &new_path).await {
+        // 이동하지 못했으면 이전 DB에서 타이머를 다시 실행
+        if let Err(resume_error) = resume_suspended_timers(&app_handle, &state, &old_path).await {
+            log::error!("[DB] Failed to resume timers: {}", resume_error);
+        }
+        return Err(e);
+    }
+
+    state
+        .worker
+        .set_passphrase(&new_path, state.worker.passphrase(&old_path));
+    *state.db_path.lock()? = Some(new_path.clone());
+    *state.locked_path.lock()? = Some(new_path.clone());
+    // 같은 데이터이므로 앱 시작 시의 타이머 복구를 다시 하지 않음
+    {
+        let mut recovered_path = state.recovered_path.lock()?;
+        if recovered_path.as_deref() == Some(old_path.as_path()) {
+            *recovered_path = Some(new_path.clone());
+        }
+    }
+
+    // 새 경로로 작업을 실행해 이전 연결을 닫은 뒤 이전 파일 삭제 (실패해도 이동은 완료)
+    resume_suspended_timers(&app_handle, &state, &new_path).await?;
+    if let Err(e) = maintenance::remove_db_files(&old_path) {
+        log::warn!("[DB] Failed to remove old DB file {}: {}", old_path.display(), e);
+    }
+    lock::release(&old_path);
+    log::info!("[DB] Moved: {} -> {}", old_path.display(), new_path.display());
+
+    crate::tray::refresh_menu(&app_handle);
+    build_status(&app_handle, &state, &new_path, true).await
+}
+
+/// DB를 새 경로로 복사하고 잠금과 설정을 새 경로로 변경 (실패하면 복사본 삭제)
+async fn copy_db(
+    app_handle: &AppHandle,
+    state: &DbState,
+    old_path: &Path,
+    new_path: &Path,
+) -> AppResult<()> {
+    if let Some(parent) = new_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // 작업 스레드에서 복사하므로 복사 중에는 다른 DB 작업이 끼어들지 않음
+    let target = new_path.to_path_buf();
+    let encrypted = crypto::is_encrypted(old_path);
+    let copied = state
+        .worker
+        .run(old_path.to_path_buf(), move |conn| {
+            if encrypted {
+                crypto::export(conn, &target, None)
+            } else {
+                maintenance::copy_verified(conn, &target)
+            }
+        })
+        .await;
+    if let Err(e) = copied {
+        let _ = maintenance::remove_db_files(new_path);
+        return Err(e);
+    }
+    if let Err(e) = lock::acquire(new_path, false) {
+        let _ = maintenance::remove_db_files(new_path);
+        return Err(e);
+    }
+
+    // 설정 저장 (워크스페이스 이름/id는 유지하고 경로만 변경)
+    let saved = load_config(app_handle).and_then(|mut config| {
+        workspace::relocate(&mut config, old_path, new_path);
+        workspace::activate_path(&mut config, new_path);
+        save_config(app_handle, &config)
+    });
+    if let Err(e) = saved {
+        lock::release(new_path);
+        let _ = maintenance::remove_db_files(new_path);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// `suspend_timers`로 저장한 타이머를 같은 실행 중에 다시 실행 (DB 이동 등)
+///
+/// 앱 시작 시 복구와 달리 `timer_resume_on_launch` 설정과 관계없이 이어서 실행한다.
+async fn resume_suspended_timers(
+    app_handle: &AppHandle,
+    state: &DbState,
+    path: &Path,
+) -> AppResult<()> {
+    let resumed = state
+        .worker
+        .run(path.to_path_buf(), |conn| {
+            timer_store::recover_running_tasks(conn, true)
+        })
+        .await?;
+    crate::timer::restore_timers(app_handle, resumed);
+    Ok(())
 }
 
 /// 로그아웃 (DB 연결 해제)
+///
+/// 실행 중인 타이머는 연결을 끊기 전에 현재 DB에 저장하고 정리한다 (다시 열면 복구됨).
 #[tauri::command]
-pub fn logout(app_handle: AppHandle, state: State<DbState>) -> Result<(), String> {
-    *state.db_path.lock().map_err(|e| e.to_string())? = None;
-    
-    // config.json에서 경로 제거
-    let file = config_file(&app_handle)?;
-    if file.exists() {
-        let data = fs::read_to_string(&file).map_err(|e| e.to_string())?;
-        let mut config: AppConfig = serde_json::from_str(&data).unwrap_or_default();
-        config.db_path = None;
-        let serialized = serde_json::to_vec_pretty(&config).map_err(|e| e.to_string())?;
-        fs::write(&file, serialized).map_err(|e| e.to_string())?;
+pub async fn logout(app_handle: AppHandle, state: State<'_, DbState>) -> AppResult<()> {
+    crate::timer::suspend_timers(&app_handle).await;
+
+    *state.db_path.lock()? = None;
+    if let Some(path) = state.locked_path.lock()?.take() {
+        lock::release(&path);
     }
+    state.worker.set_read_only_path(None);
+    *state.read_only_opened.lock()? = None;
+    state.worker.clear_passphrases();
+    state.worker.close();
     
+    // config.json에서 경로 제거 (워크스페이스 목록은 유지)
+    let mut config = load_config(&app_handle)?;
+    config.db_path = None;
+    config.active_workspace_id = None;
+    save_config(&app_handle, &config)?;
+    
+    crate::tray::refresh_menu(&app_handle);
     Ok(())
 }
 
+/// DB 점검 (repair가 true면 백업 후 복구 가능한 문제를 수정하고 다시 점검)
+///
+/// 파일 손상(integrity_check 실패)이 있으면 수정하지 않고 결과만 반환한다.
+#[tauri::command]
+pub async fn check_db(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    repair: Option<bool>,
+) -> AppResult<DbCheckReport> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    let backup_dir = backup::backup_dir(&app_handle)?;
+    let repair = repair.unwrap_or(false);
+    if repair {
+        state.check_writable(&db_path)?;
+    }
+
+    let report = state
+        .worker
+        .run(db_path.clone(), move |conn| {
+            let report = integrity::check_db(conn)?;
+            log::info!(
+                "[DB] Check: integrity_errors={}, issues={}",
+                report.integrity_errors.len(),
+                report.issues.len()
+            );
+            if !repair || report.ok {
+                return Ok(report);
+            }
+            if !report.integrity_errors.is_empty() {
+                log::warn!("[DB] Integrity check failed, skipping repair");
+                return Ok(report);
+            }
+
+            let backup = backup::backup_and_prune(conn, &db_path, &backup_dir, BackupReason::PreRepair)?;
+            let repaired_rows = integrity::repair_db(conn, &report)?;
+            log::info!("[DB] Repaired rows: {}", repaired_rows);
+
+            let mut report = integrity::check_db(conn)?;
+            report.repaired_rows = repaired_rows;
+            report.backup_file = Some(backup.file_name);
+            Ok(report)
+        })
+        .await?;
+
+    if report.repaired_rows > 0 {
+        crate::tray::refresh_menu(&app_handle);
+        let _ = app_handle.emit("tasks-changed", ());
+    }
+    Ok(report)
+}
+
+/// DB 정리 (VACUUM/ANALYZE) 후 줄어든 크기 반환
+#[tauri::command]
+pub async fn compact_db(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+) -> AppResult<CompactReport> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    state.check_writable(&db_path)?;
+    let size_before = maintenance::total_size_bytes(&db_path);
+
+    state
+        .worker
+        .run(db_path.clone(), |conn| maintenance::compact(conn))
+        .await?;
+
+    let size_after = maintenance::total_size_bytes(&db_path);
+    let reclaimed_bytes = size_before.saturating_sub(size_after);
+    log::info!("[DB] Compacted: {} -> {} bytes", size_before, size_after);
+
+    Ok(CompactReport {
+        size_before,
+        size_after,
+        reclaimed_bytes,
+    })
+}
+
 // ============================================================================
-// Task CRUD 커맨드
+// 암호화 커맨드
 // ============================================================================
 
-/// Task 목록 조회
+/// 현재 DB 암호화 (암호화된 사본을 만들어 검증한 뒤 기존 파일과 교체)
+///
+/// 기존 백업은 평문으로 남고 이후 백업부터 암호화된다.
 #[tauri::command]
-pub fn list_tasks(
+pub async fn encrypt_db(
     app_handle: AppHandle,
-    state: State<DbState>,
-    status: Option<String>,
-) -> Result<Vec<Task>, String> {
-    let conn = get_connection(&app_handle, &state)?;
+    state: State<'_, DbState>,
+    passphrase: String,
+) -> AppResult<DbStatus> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    state.check_writable(&db_path)?;
+    if crypto::is_encrypted(&db_path) {
+        return Err(AppError::InvalidInput("이미 암호화된 DB입니다.".to_string()));
+    }
+    validate_passphrase(&passphrase)?;
 
-    let sql = if status.is_some() {
-        "SELECT * FROM tbl_task WHERE status = ?1 ORDER BY is_important DESC, created_at DESC"
-    } else {
-        "SELECT * FROM tbl_task ORDER BY is_important DESC, created_at DESC"
-    };
+    rewrite_db(&state, &db_path, passphrase).await?;
+    log::info!("[DB] Encrypted: {}", db_path.display());
+    build_status(&app_handle, &state, &db_path, true).await
+}
+
+/// DB 암호 변경 (새 암호로 암호화된 사본을 만들어 기존 파일과 교체)
+///
+/// 이전 암호로 만든 백업은 복원할 수 없으므로 필요하면 먼저 복호화해 내보낸다.
+#[tauri::command]
+pub async fn change_passphrase(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    current: String,
+    passphrase: String,
+) -> AppResult<DbStatus> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    state.check_writable(&db_path)?;
+    if !crypto::is_encrypted(&db_path) {
+        return Err(AppError::InvalidInput("암호화되지 않은 DB입니다.".to_string()));
+    }
+    if state.worker.passphrase(&db_path).as_deref() != Some(current.as_str()) {
+        return Err(AppError::WrongPassphrase);
+    }
+    validate_passphrase(&passphrase)?;
 
-    let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+    rewrite_db(&state, &db_path, passphrase).await?;
+    log::info!("[DB] Passphrase changed: {}", db_path.display());
+    build_status(&app_handle, &state, &db_path, true).await
+}
 
-    let task_iter = if let Some(ref s) = status {
-        stmt.query_map([s], map_task_row)
-    } else {
-        stmt.query_map([], map_task_row)
+/// 현재 DB를 평문 사본으로 내보내기 (현재 DB는 그대로 유지)
+#[tauri::command]
+pub async fn export_decrypted_db(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    path: String,
+) -> AppResult<()> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    let target = PathBuf::from(&path);
+    if target.exists() {
+        return Err(AppError::InvalidInput("대상 경로에 이미 파일이 있습니다.".to_string()));
     }
-    .map_err(|e| e.to_string())?;
-
-    let mut tasks = Vec::new();
-    for task_result in task_iter {
-        let mut task = task_result.map_err(|e| e.to_string())?;
-        // 관계 데이터 로드
-        task.tags = load_task_tags(&conn, &task.id)?;
-        task.memos = load_task_memos(&conn, &task.id)?;
-        task.notes = load_task_notes(&conn, &task.id)?;
-        task.run_history = load_task_run_history(&conn, &task.id)?;
-        task.time_extensions = load_task_time_extensions(&conn, &task.id)?;
-        task.action_history = load_task_action_history(&conn, &task.id)?;
-        tasks.push(task);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
     }
 
-    Ok(tasks)
+    let export_path = target.clone();
+    let exported = state
+        .worker
+        .run(db_path, move |conn| crypto::export(conn, &export_path, Some("")))
+        .await;
+    if let Err(e) = exported {
+        let _ = maintenance::remove_db_files(&target);
+        return Err(e);
+    }
+    log::info!("[DB] Exported decrypted copy: {}", target.display());
+    Ok(())
 }
 
-/// Task 단건 조회
+fn validate_passphrase(passphrase: &str) -> AppResult<()> {
+    if passphrase.is_empty() {
+        return Err(AppError::InvalidInput("암호를 입력해주세요.".to_string()));
+    }
+    Ok(())
+}
+
+/// 새 암호로 암호화된 사본을 만들어 검증한 뒤 기존 DB 파일과 교체 (실패하면 사본 삭제)
+async fn rewrite_db(state: &DbState, db_path: &Path, passphrase: String) -> AppResult<()> {
+    let temp_path = crypto::temp_path(db_path);
+    maintenance::remove_db_files(&temp_path)?;
+
+    let target = temp_path.clone();
+    let key = passphrase.clone();
+    let exported = state
+        .worker
+        .run(db_path.to_path_buf(), move |conn| {
+            crypto::export(conn, &target, Some(&key))
+        })
+        .await;
+    if let Err(e) = exported {
+        let _ = maintenance::remove_db_files(&temp_path);
+        return Err(e);
+    }
+
+    state
+        .worker
+        .replace_file(db_path.to_path_buf(), temp_path, Some(passphrase))
+        .await
+}
+
+// ============================================================================
+// 워크스페이스 커맨드
+// ============================================================================
+
+/// 워크스페이스 목록
+#[tauri::command]
+pub fn list_workspaces(app_handle: AppHandle) -> AppResult<WorkspaceList> {
+    let config = load_config(&app_handle)?;
+    Ok(WorkspaceList {
+        active_id: config.active_workspace_id,
+        workspaces: config.workspaces,
+    })
+}
+
+/// 워크스페이스 추가 (파일이 없으면 새 DB 생성, 활성 워크스페이스는 그대로)
 #[tauri::command]
-pub fn get_task(
+pub async fn add_workspace(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    name: String,
+    path: String,
+) -> AppResult<Workspace> {
+    let db_path = PathBuf::from(&path);
+    let mut config = load_config(&app_handle)?;
+    let workspace = workspace::add(&mut config, &name, &db_path)?;
+
+    if !db_path.exists() {
+        if let Some(parent) = db_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        migrate(&app_handle, &state, &db_path).await?;
+    }
+
+    save_config(&app_handle, &config)?;
+    crate::tray::refresh_menu(&app_handle);
+    Ok(workspace)
+}
+
+/// 워크스페이스 이름 변경
+#[tauri::command]
+pub fn rename_workspace(app_handle: AppHandle, id: String, name: String) -> AppResult<Workspace> {
+    let mut config = load_config(&app_handle)?;
+    let workspace = workspace::rename(&mut config, &id, &name)?;
+    save_config(&app_handle, &config)?;
+    crate::tray::refresh_menu(&app_handle);
+    Ok(workspace)
+}
+
+/// 워크스페이스 제거 (DB 파일은 삭제하지 않음)
+#[tauri::command]
+pub fn remove_workspace(app_handle: AppHandle, id: String) -> AppResult<()> {
+    let mut config = load_config(&app_handle)?;
+    workspace::remove(&mut config, &id)?;
+    save_config(&app_handle, &config)?;
+    crate::tray::refresh_menu(&app_handle);
+    Ok(())
+}
+
+/// 활성 워크스페이스 전환 (암호화된 DB는 passphrase 필요, 이미 입력한 DB는 생략 가능)
+#[tauri::command]
+pub async fn switch_workspace(
     app_handle: AppHandle,
-    state: State<DbState>,
     id: String,
-) -> Result<Task, String> {
-    let conn = get_connection(&app_handle, &state)?;
+    passphrase: Option<String>,
+) -> AppResult<DbStatus> {
+    activate_workspace(&app_handle, &id, passphrase).await
+}
 
-    let mut stmt = conn
-        .prepare("SELECT * FROM tbl_task WHERE id = ?1")
-        .map_err(|e| e.to_string())?;
+/// 워크스페이스 전환 (커맨드/트레이 공용)
+/// 트레이에서 전환한 경우에도 화면이 갱신되도록 `workspace-changed`, `tasks-changed` 전송
+pub async fn activate_workspace(
+    app_handle: &AppHandle,
+    id: &str,
+    passphrase: Option<String>,
+) -> AppResult<DbStatus> {
+    let config = load_config(app_handle)?;
+    let db_path = PathBuf::from(&workspace::find(&config, id)?.path);
+    if !db_path.exists() {
+        return Err(AppError::db_file_not_found(&db_path));
+    }
+
+    let state = app_handle.state::<DbState>();
+    unlock(&state, &db_path, passphrase)?;
+    let status = open_db(app_handle, &state, &db_path, false).await?;
+    log::info!("[DB] Workspace switched: {}", db_path.display());
+
+    let _ = app_handle.emit("workspace-changed", &status);
+    let _ = app_handle.emit("tasks-changed", ());
+    Ok(status)
+}
+
+// ============================================================================
+// 백업 커맨드
+// ============================================================================
+
+/// 현재 DB의 백업 목록 (최신순)
+#[tauri::command]
+pub async fn list_backups(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+) -> AppResult<Vec<BackupInfo>> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    backup::list_backups(&backup::backup_dir(&app_handle)?, &db_path)
+}
+
+/// 백업으로 현재 DB 복원
+///
+/// 현재 DB를 먼저 백업(pre-restore)한 뒤 online backup API로 내용을 덮어쓴다.
+/// 암호화된 DB는 검증한 사본을 만들어 파일을 교체한다.
+/// 실행 중인 타이머는 기록 없이 정리하고 복원된 DB의 체크포인트로 다시 복구한다.
+#[tauri::command]
+pub async fn restore_backup(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    file_name: String,
+) -> AppResult<DbStatus> {
+    let db_path = current_db_path(&app_handle, &state)?;
+    state.check_writable(&db_path)?;
+    let backup_dir = backup::backup_dir(&app_handle)?;
+
+    // 백업 디렉토리 밖의 파일은 복원하지 않음
+    let backup = backup::list_backups(&backup_dir, &db_path)?
+        .into_iter()
+        .find(|b| b.file_name == file_name)
+        .ok_or_else(|| AppError::NotFound("백업 파일을 찾을 수 없습니다.".to_string()))?;
+
+    crate::timer::clear_timers(&app_handle).await;
+
+    let source = PathBuf::from(&backup.path);
+    let target = db_path.clone();
+    if crypto::is_encrypted(&db_path) {
+        // online backup API를 쓸 수 없으므로 검증한 사본을 만든 뒤 파일을 교체 (실패하면 현재 DB 유지)
+        let temp_path = crypto::temp_path(&db_path);
+        maintenance::remove_db_files(&temp_path)?;
+        let export_path = temp_path.clone();
+        let exported = state
+            .worker
+            .run(db_path.clone(), move |conn| {
+                backup::backup_and_prune(conn, &target, &backup_dir, BackupReason::PreRestore)?;
+                crypto::export_backup(conn, &source, &export_path)
+            })
+            .await;
+        if let Err(e) = exported {
+            let _ = maintenance::remove_db_files(&temp_path);
+            return Err(e);
+        }
+        let passphrase = state.worker.passphrase(&db_path);
+        state
+            .worker
+            .replace_file(db_path.clone(), temp_path, passphrase)
+            .await?;
+    } else {
+        state
+            .worker
+            .run(db_path.clone(), move |conn| {
+                backup::backup_and_prune(conn, &target, &backup_dir, BackupReason::PreRestore)?;
+                conn.restore(
+                    rusqlite::DatabaseName::Main,
+                    &source,
+                    None::<fn(rusqlite::backup::Progress)>,
+                )?;
+                Ok(())
+            })
+            .await?;
+    }
+    // 이전 버전 앱에서 만든 백업일 수 있으므로 스키마 갱신
+    state.worker.run(db_path.clone(), |conn| run_migrations(conn)).await?;
+    log::info!("[DB] Restored from backup {}", backup.file_name);
+
+    // 복원된 DB 기준으로 타이머 복구
+    *state.recovered_path.lock()? = None;
+    recover_running_tasks(&app_handle, &state, &db_path).await?;
+    let _ = app_handle.emit("tasks-changed", ());
+
+    build_status(&app_handle, &state, &db_path, true).await
+}
+
+// ============================================================================
+// Task CRUD 커맨드
+// ============================================================================
+
+/// Task 목록 조회
+#[tauri::command]
+pub async fn list_tasks(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    status: Option<String>,
+) -> AppResult<Vec<Task>> {
+    state
+        .run(&app_handle, move |conn| {
+            let sql = if status.is_some() {
+                "SELECT * FROM tbl_task WHERE status = ?1 ORDER BY is_important DESC, created_at DESC"
+            } else {
+                "SELECT * FROM tbl_task ORDER BY is_important DESC, created_at DESC"
+            };
+
+            let mut stmt = conn.prepare_cached(sql)?;
 
-    let mut task = stmt
-        .query_row([&id], map_task_row)
-        .map_err(|e| e.to_string())?;
+            let task_iter = if let Some(ref s) = status {
+                stmt.query_map([s], map_task_row)
+            } else {
+                stmt.query_map([], map_task_row)
+            }?;
+
+            let mut tasks = Vec::new();
+            for task_result in task_iter {
+                let mut task = task_result?;
+                // 관계 데이터 로드
+                task.tags = load_task_tags(conn, &task.id)?;
+                task.memos = load_task_memos(conn, &task.id)?;
+                task.notes = load_task_notes(conn, &task.id)?;
+                task.run_history = load_task_run_history(conn, &task.id)?;
+                task.time_extensions = load_task_time_extensions(conn, &task.id)?;
+                task.action_history = load_task_action_history(conn, &task.id)?;
+                tasks.push(task);
+            }
 
-    task.tags = load_task_tags(&conn, &task.id)?;
-    task.memos = load_task_memos(&conn, &task.id)?;
-    task.notes = load_task_notes(&conn, &task.id)?;
-    task.run_history = load_task_run_history(&conn, &task.id)?;
-    task.time_extensions = load_task_time_extensions(&conn, &task.id)?;
-    task.action_history = load_task_action_history(&conn, &task.id)?;
+            Ok(tasks)
+        })
+        .await
+}
 
-    Ok(task)
+/// Task 단건 조회
+#[tauri::command]
+pub async fn get_task(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    id: String,
+) -> AppResult<Task> {
+    state
+        .run(&app_handle, move |conn| {
+            let mut stmt = conn
+                .prepare_cached("SELECT * FROM tbl_task WHERE id = ?1")?;
+
+            let mut task = stmt
+                .query_row([&id], map_task_row)?;
+
+            task.tags = load_task_tags(conn, &task.id)?;
+            task.memos = load_task_memos(conn, &task.id)?;
+            task.notes = load_task_notes(conn, &task.id)?;
+            task.run_history = load_task_run_history(conn, &task.id)?;
+            task.time_extensions = load_task_time_extensions(conn, &task.id)?;
+            task.action_history = load_task_action_history(conn, &task.id)?;
+
+            Ok(task)
+        })
+        .await
 }
 
 /// Task 생성
 #[tauri::command]
-pub fn create_task(
+pub async fn create_task(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     input: CreateTaskInput,
-) -> Result<String, String> {
-    let conn = get_connection(&app_handle, &state)?;
-    let id = uuid::Uuid::new_v4().to_string();
-    let priority = input.priority.unwrap_or_default();
+) -> AppResult<String> {
+    let id = state
+        .run_mut(&app_handle, move |conn| {
+            let id = uuid::Uuid::new_v4().to_string();
+            let priority = input.priority.unwrap_or_default();
+
+            conn.execute(
+                r#"
+                INSERT INTO tbl_task (id, title, description, url, priority, expected_duration, target_date)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                "#,
+                rusqlite::params![
+                    id,
+                    input.title,
+                    input.description,
+                    input.url,
+                    priority.to_string(),
+                    // 예상 시간이 없는 task는 스톱워치로 측정
+                    input.expected_duration,
+                    input.target_date,
+                ],
+            )?;
 
-    conn.execute(
-        r#"
-        INSERT INTO tbl_task (id, title, description, url, priority, expected_duration, target_date)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-        "#,
-        rusqlite::params![
-            id,
-            input.title,
-            input.description,
-            input.url,
-            priority.to_string(),
-            input.expected_duration.unwrap_or(5),
-            input.target_date,
-        ],
-    )
-    .map_err(|e| e.to_string())?;
-
-    // 태그 추가
-    if let Some(tags) = input.tags {
-        for tag in tags {
-            add_tag_internal(&conn, &id, &tag)?;
-        }
-    }
+            // 태그 추가
+            if let Some(tags) = input.tags {
+                for tag in tags {
+                    add_tag_internal(conn, &id, &tag)?;
+                }
+            }
 
-    // 생성 액션 히스토리 기록
-    add_action_history_internal(&conn, &id, "CREATED", None, Some("INBOX"), None)?;
+            // 생성 액션 히스토리 기록
+            add_action_history_internal(conn, &id, "CREATED", None, Some("INBOX"), None)?;
 
+            Ok(id)
+        })
+        .await?;
+    crate::tray::refresh_menu(&app_handle);
     Ok(id)
 }
 
 /// Task 수정
 #[tauri::command]
-pub fn update_task(
+pub async fn update_task(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     input: UpdateTaskInput,
-) -> Result<(), String> {
+) -> AppResult<()> {
     // 디버그: 입력값 확인
-    println!("[update_task] Input: {:?}", input);
-    println!("[update_task] remaining_time_seconds: {:?}", input.remaining_time_seconds);
+    log::info!("[update_task] Input: {:?}", input);
     
-    let conn = get_connection(&app_handle, &state)?;
+    state
+        .run_mut(&app_handle, move |conn| {
+            // 상태 변경 시 액션 히스토리 기록을 위해 현재 상태 조회
+            let previous_status: Option<String> = if input.status.is_some() {
+                conn.query_row(
+                    "SELECT status FROM tbl_task WHERE id = ?1",
+                    [&input.id],
+                    |row| row.get(0),
+                ).ok()
+            } else {
+                None
+            };
 
-    // 상태 변경 시 액션 히스토리 기록을 위해 현재 상태 조회
-    let previous_status: Option<String> = if input.status.is_some() {
-        conn.query_row(
-            "SELECT status FROM tbl_task WHERE id = ?1",
-            [&input.id],
-            |row| row.get(0),
-        ).ok()
-    } else {
-        None
-    };
+            // 목표일 변경 시 액션 히스토리 기록을 위해 현재 목표일 조회
+            let previous_target_date: Option<String> = if input.target_date.is_some() {
+                conn.query_row(
+                    "SELECT target_date FROM tbl_task WHERE id = ?1",
+                    [&input.id],
+                    |row| row.get(0),
+                ).ok()
+            } else {
+                None
+            };
 
-    // 목표일 변경 시 액션 히스토리 기록을 위해 현재 목표일 조회
-    let previous_target_date: Option<String> = if input.target_date.is_some() {
-        conn.query_row(
-            "SELECT target_date FROM tbl_task WHERE id = ?1",
-            [&input.id],
-            |row| row.get(0),
-        ).ok()
-    } else {
-        None
-    };
+            let mut updates = vec!["updated_at = datetime('now')".to_string()];
+            let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
 
-    let mut updates = vec!["updated_at = datetime('now')".to_string()];
-    let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
+            macro_rules! add_update {
+                ($field:expr, $value:expr) => {
+                    if let Some(v) = $value {
+                        updates.push(format!("{} = ?{}", $field, params.len() + 1));
+                        params.push(Box::new(v));
+                    }
+                };
+            }
 
-    macro_rules! add_update {
-        ($field:expr, $value:expr) => {
-            if let Some(v) = $value {
-                updates.push(format!("{} = ?{}", $field, params.len() + 1));
-                params.push(Box::new(v));
+            add_update!("title", input.title);
+            add_update!("description", input.description);
+            add_update!("url", input.url);
+            if let Some(p) = input.priority {
+                updates.push(format!("priority = ?{}", params.len() + 1));
+                params.push(Box::new(p.to_string()));
+            }
+            let new_status = input.status.clone();
+            if let Some(s) = input.status {
+                updates.push(format!("status = ?{}", params.len() + 1));
+                params.push(Box::new(s.to_string()));
+            }
+            add_update!("total_time_spent", input.total_time_spent);
+            add_update!("expected_duration", input.expected_duration);
+            add_update!("remaining_time_seconds", input.remaining_time_seconds);
+            // 목표일 변경 감지를 위해 클론 저장
+            let new_target_date = input.target_date.clone();
+            add_update!("target_date", input.target_date);
+            if let Some(i) = input.is_important {
+                updates.push(format!("is_important = ?{}", params.len() + 1));
+                params.push(Box::new(if i { 1 } else { 0 }));
+            }
+            add_update!("completed_at", input.completed_at);
+            add_update!("last_paused_at", input.last_paused_at);
+            add_update!("last_run_at", input.last_run_at);
+
+            let sql = format!(
+                "UPDATE tbl_task SET {} WHERE id = ?{}",
+                updates.join(", "),
+                params.len() + 1
+            );
+            params.push(Box::new(input.id.clone()));
+
+            let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            conn.execute(&sql, params_refs.as_slice())?;
+
+            // 상태 변경 시 액션 히스토리 기록 (동일 상태로 변경되는 경우는 기록하지 않음)
+            if let Some(new_status) = new_status {
+                let new_status_str = new_status.to_string();
+                // 이전 상태와 새 상태가 다를 때만 히스토리 기록
+                if previous_status.as_ref().map(|s| s.as_str()) != Some(new_status_str.as_str()) {
+                    let action_type = status_action_type(&new_status_str);
+                    add_action_history_internal(
+                        conn,
+                        &input.id,
+                        action_type,
+                        previous_status.as_deref(),
+                        Some(&new_status_str),
+                        None,
+                    )?;
+                }
             }
-        };
-    }
 
-    add_update!("title", input.title);
-    add_update!("description", input.description);
-    add_update!("url", input.url);
-    if let Some(p) = input.priority {
-        updates.push(format!("priority = ?{}", params.len() + 1));
-        params.push(Box::new(p.to_string()));
-    }
-    let new_status = input.status.clone();
-    if let Some(s) = input.status {
-        updates.push(format!("status = ?{}", params.len() + 1));
-        params.push(Box::new(s.to_string()));
-    }
-    add_update!("total_time_spent", input.total_time_spent);
-    add_update!("expected_duration", input.expected_duration);
-    add_update!("remaining_time_seconds", input.remaining_time_seconds);
-    // 목표일 변경 감지를 위해 클론 저장
-    let new_target_date = input.target_date.clone();
-    add_update!("target_date", input.target_date);
-    if let Some(i) = input.is_important {
-        updates.push(format!("is_important = ?{}", params.len() + 1));
-        params.push(Box::new(if i { 1 } else { 0 }));
-    }
-    add_update!("completed_at", input.completed_at);
-    add_update!("last_paused_at", input.last_paused_at);
-    add_update!("last_run_at", input.last_run_at);
-
-    let sql = format!(
-        "UPDATE tbl_task SET {} WHERE id = ?{}",
-        updates.join(", "),
-        params.len() + 1
-    );
-    params.push(Box::new(input.id.clone()));
-
-    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
-    conn.execute(&sql, params_refs.as_slice())
-        .map_err(|e| e.to_string())?;
-
-    // 상태 변경 시 액션 히스토리 기록 (동일 상태로 변경되는 경우는 기록하지 않음)
-    if let Some(new_status) = new_status {
-        let new_status_str = new_status.to_string();
-        // 이전 상태와 새 상태가 다를 때만 히스토리 기록
-        if previous_status.as_ref().map(|s| s.as_str()) != Some(new_status_str.as_str()) {
-            let action_type = match new_status_str.as_str() {
-                "IN_PROGRESS" => "STARTED",
-                "PAUSED" => "PAUSED",
-                "COMPLETED" => "COMPLETED",
-                "ARCHIVED" => "ARCHIVED",
-                "INBOX" => "RESTORED",
-                _ => "STATUS_CHANGED",
-            };
-            add_action_history_internal(
-                &conn,
-                &input.id,
-                action_type,
-                previous_status.as_deref(),
-                Some(&new_status_str),
-                None,
-            )?;
-        }
-    }
+            // 목표일 변경 시 액션 히스토리 기록
+            if let Some(new_target_date) = &new_target_date {
+                // 목표일이 실제로 변경되었는지 확인
+                let changed = match &previous_target_date {
+                    Some(prev) => prev != new_target_date,
+                    None => true, // 이전에 목표일이 없었는데 새로 설정된 경우
+                };
 
-    // 목표일 변경 시 액션 히스토리 기록
-    if let Some(new_target_date) = &new_target_date {
-        // 목표일이 실제로 변경되었는지 확인
-        let changed = match &previous_target_date {
-            Some(prev) => prev != new_target_date,
-            None => true, // 이전에 목표일이 없었는데 새로 설정된 경우
-        };
-        
-        if changed {
-            // metadata에 이전/새 목표일 정보 저장
-            let metadata = if let Some(prev) = &previous_target_date {
-                format!(r#"{{"previousTargetDate":"{}","newTargetDate":"{}"}}"#, prev, new_target_date)
-            } else {
-                format!(r#"{{"newTargetDate":"{}"}}"#, new_target_date)
-            };
-            
-            add_action_history_internal(
-                &conn,
-                &input.id,
-                "TARGET_DATE_CHANGED",
-                None,
-                None,
-                Some(&metadata),
-            )?;
-        }
-    }
+                if changed {
+                    // metadata에 이전/새 목표일 정보 저장
+                    let metadata = if let Some(prev) = &previous_target_date {
+                        format!(r#"{{"previousTargetDate":"{}","newTargetDate":"{}"}}"#, prev, new_target_date)
+                    } else {
+                        format!(r#"{{"newTargetDate":"{}"}}"#, new_target_date)
+                    };
+
+                    add_action_history_internal(
+                        conn,
+                        &input.id,
+                        "TARGET_DATE_CHANGED",
+                        None,
+                        None,
+                        Some(&metadata),
+                    )?;
+                }
+            }
 
+            Ok(())
+        })
+        .await?;
+    crate::tray::refresh_menu(&app_handle);
     Ok(())
 }
 
 /// Task 삭제
 #[tauri::command]
-pub fn delete_task(
+pub async fn delete_task(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     id: String,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-    conn.execute("DELETE FROM tbl_task WHERE id = ?1", [&id])
-        .map_err(|e| e.to_string())?;
+) -> AppResult<()> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            conn.execute("DELETE FROM tbl_task WHERE id = ?1", [&id])?;
+            Ok(())
+        })
+        .await?;
+    crate::tray::refresh_menu(&app_handle);
     Ok(())
 }
 
@@ -508,168 +1182,174 @@ pub fn delete_task(
 
 /// 메모 추가
 #[tauri::command]
-pub fn add_task_memo(
+pub async fn add_task_memo(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     task_id: String,
     content: String,
-) -> Result<TaskMemo, String> {
-    let conn = get_connection(&app_handle, &state)?;
-    let id = uuid::Uuid::new_v4().to_string();
-
-    conn.execute(
-        "INSERT INTO tbl_task_memo (id, task_id, content) VALUES (?1, ?2, ?3)",
-        rusqlite::params![id, task_id, content],
-    )
-    .map_err(|e| e.to_string())?;
+) -> AppResult<TaskMemo> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            let id = uuid::Uuid::new_v4().to_string();
+
+            conn.execute(
+                "INSERT INTO tbl_task_memo (id, task_id, content) VALUES (?1, ?2, ?3)",
+                rusqlite::params![id, task_id, content],
+            )?;
 
-    let memo = conn
-        .query_row(
-            "SELECT id, task_id, content, created_at FROM tbl_task_memo WHERE id = ?1",
-            [&id],
-            |row| {
-                Ok(TaskMemo {
-                    id: row.get(0)?,
-                    task_id: row.get(1)?,
-                    content: row.get(2)?,
-                    created_at: row.get(3)?,
-                })
-            },
-        )
-        .map_err(|e| e.to_string())?;
-
-    Ok(memo)
+            let memo = conn
+                .query_row(
+                    "SELECT id, task_id, content, created_at FROM tbl_task_memo WHERE id = ?1",
+                    [&id],
+                    |row| {
+                        Ok(TaskMemo {
+                            id: row.get(0)?,
+                            task_id: row.get(1)?,
+                            content: row.get(2)?,
+                            created_at: row.get(3)?,
+                        })
+                    },
+                )?;
+
+            Ok(memo)
+        })
+        .await
 }
 
 /// 노트 추가
 #[tauri::command]
-pub fn add_task_note(
+pub async fn add_task_note(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     task_id: String,
     title: String,
     content: String,
-) -> Result<TaskNote, String> {
-    let conn = get_connection(&app_handle, &state)?;
-    let id = uuid::Uuid::new_v4().to_string();
-
-    conn.execute(
-        "INSERT INTO tbl_task_note (id, task_id, title, content) VALUES (?1, ?2, ?3, ?4)",
-        rusqlite::params![id, task_id, title, content],
-    )
-    .map_err(|e| e.to_string())?;
+) -> AppResult<TaskNote> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            let id = uuid::Uuid::new_v4().to_string();
+
+            conn.execute(
+                "INSERT INTO tbl_task_note (id, task_id, title, content) VALUES (?1, ?2, ?3, ?4)",
+                rusqlite::params![id, task_id, title, content],
+            )?;
 
-    let note = conn
-        .query_row(
-            "SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE id = ?1",
-            [&id],
-            |row| {
-                Ok(TaskNote {
-                    id: row.get(0)?,
-                    task_id: row.get(1)?,
-                    title: row.get(2)?,
-                    content: row.get(3)?,
-                    created_at: row.get(4)?,
-                    updated_at: row.get(5)?,
-                })
-            },
-        )
-        .map_err(|e| e.to_string())?;
-
-    Ok(note)
+            let note = conn
+                .query_row(
+                    "SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE id = ?1",
+                    [&id],
+                    |row| {
+                        Ok(TaskNote {
+                            id: row.get(0)?,
+                            task_id: row.get(1)?,
+                            title: row.get(2)?,
+                            content: row.get(3)?,
+                            created_at: row.get(4)?,
+                            updated_at: row.get(5)?,
+                        })
+                    },
+                )?;
+
+            Ok(note)
+        })
+        .await
 }
 
 /// 노트 업데이트
 #[tauri::command]
-pub fn update_task_note(
+pub async fn update_task_note(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     note_id: String,
     title: Option<String>,
     content: Option<String>,
-) -> Result<TaskNote, String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    let mut updates = vec!["updated_at = datetime('now')".to_string()];
-    let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
-
-    if let Some(t) = title {
-        updates.push(format!("title = ?{}", params.len() + 1));
-        params.push(Box::new(t));
-    }
-    if let Some(c) = content {
-        updates.push(format!("content = ?{}", params.len() + 1));
-        params.push(Box::new(c));
-    }
-
-    let sql = format!(
-        "UPDATE tbl_task_note SET {} WHERE id = ?{}",
-        updates.join(", "),
-        params.len() + 1
-    );
-    params.push(Box::new(note_id.clone()));
-
-    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
-    conn.execute(&sql, params_refs.as_slice())
-        .map_err(|e| e.to_string())?;
+) -> AppResult<TaskNote> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            let mut updates = vec!["updated_at = datetime('now')".to_string()];
+            let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
+
+            if let Some(t) = title {
+                updates.push(format!("title = ?{}", params.len() + 1));
+                params.push(Box::new(t));
+            }
+            if let Some(c) = content {
+                updates.push(format!("content = ?{}", params.len() + 1));
+                params.push(Box::new(c));
+            }
 
-    let note = conn
-        .query_row(
-            "SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE id = ?1",
-            [&note_id],
-            |row| {
-                Ok(TaskNote {
-                    id: row.get(0)?,
-                    task_id: row.get(1)?,
-                    title: row.get(2)?,
-                    content: row.get(3)?,
-                    created_at: row.get(4)?,
-                    updated_at: row.get(5)?,
-                })
-            },
-        )
-        .map_err(|e| e.to_string())?;
-
-    Ok(note)
+            let sql = format!(
+                "UPDATE tbl_task_note SET {} WHERE id = ?{}",
+                updates.join(", "),
+                params.len() + 1
+            );
+            params.push(Box::new(note_id.clone()));
+
+            let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            conn.execute(&sql, params_refs.as_slice())?;
+
+            let note = conn
+                .query_row(
+                    "SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE id = ?1",
+                    [&note_id],
+                    |row| {
+                        Ok(TaskNote {
+                            id: row.get(0)?,
+                            task_id: row.get(1)?,
+                            title: row.get(2)?,
+                            content: row.get(3)?,
+                            created_at: row.get(4)?,
+                            updated_at: row.get(5)?,
+                        })
+                    },
+                )?;
+
+            Ok(note)
+        })
+        .await
 }
 
 /// 태그 추가
 #[tauri::command]
-pub fn add_task_tag(
+pub async fn add_task_tag(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     task_id: String,
     tag: String,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-    add_tag_internal(&conn, &task_id, &tag)
+) -> AppResult<()> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            add_tag_internal(conn, &task_id, &tag)
+        })
+        .await
 }
 
-fn add_tag_internal(conn: &Connection, task_id: &str, tag: &str) -> Result<(), String> {
+fn add_tag_internal(conn: &Connection, task_id: &str, tag: &str) -> AppResult<()> {
     let id = uuid::Uuid::new_v4().to_string();
     conn.execute(
         "INSERT OR IGNORE INTO tbl_task_tag (id, task_id, tag) VALUES (?1, ?2, ?3)",
         rusqlite::params![id, task_id, tag],
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
     Ok(())
 }
 
 /// 태그 제거
 #[tauri::command]
-pub fn remove_task_tag(
+pub async fn remove_task_tag(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     task_id: String,
     tag: String,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-    conn.execute(
-        "DELETE FROM tbl_task_tag WHERE task_id = ?1 AND tag = ?2",
-        rusqlite::params![task_id, tag],
-    )
-    .map_err(|e| e.to_string())?;
-    Ok(())
+) -> AppResult<()> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            conn.execute(
+                "DELETE FROM tbl_task_tag WHERE task_id = ?1 AND tag = ?2",
+                rusqlite::params![task_id, tag],
+            )?;
+            Ok(())
+        })
+        .await
 }
 
 // ============================================================================
@@ -678,91 +1358,96 @@ pub fn remove_task_tag(
 
 /// 실행 시작
 #[tauri::command]
-pub fn start_task_run(
+pub async fn start_task_run(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     task_id: String,
-) -> Result<String, String> {
-    let conn = get_connection(&app_handle, &state)?;
-    let id = uuid::Uuid::new_v4().to_string();
-
-    conn.execute(
-        r#"
-        INSERT INTO tbl_task_run_history (id, task_id, started_at, duration, end_type)
-        VALUES (?1, ?2, datetime('now'), 0, 'running')
-        "#,
-        rusqlite::params![id, task_id],
-    )
-    .map_err(|e| e.to_string())?;
+) -> AppResult<String> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            let id = uuid::Uuid::new_v4().to_string();
+
+            conn.execute(
+                r#"
+                INSERT INTO tbl_task_run_history (id, task_id, started_at, duration, end_type)
+                VALUES (?1, ?2, datetime('now'), 0, 'running')
+                "#,
+                rusqlite::params![id, task_id],
+            )?;
 
-    // Task의 last_run_at 업데이트
-    conn.execute(
-        "UPDATE tbl_task SET last_run_at = datetime('now'), updated_at = datetime('now') WHERE id = ?1",
-        [&task_id],
-    )
-    .map_err(|e| e.to_string())?;
+            // Task의 last_run_at 업데이트
+            conn.execute(
+                "UPDATE tbl_task SET last_run_at = datetime('now'), updated_at = datetime('now') WHERE id = ?1",
+                [&task_id],
+            )?;
 
-    Ok(id)
+            Ok(id)
+        })
+        .await
 }
 
 /// 실행 종료
 #[tauri::command]
-pub fn end_task_run(
+pub async fn end_task_run(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     run_id: String,
     end_type: String,
     duration: i64,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    conn.execute(
-        r#"
-        UPDATE tbl_task_run_history 
-        SET ended_at = datetime('now'), duration = ?2, end_type = ?3
-        WHERE id = ?1
-        "#,
-        rusqlite::params![run_id, duration, end_type],
-    )
-    .map_err(|e| e.to_string())?;
+) -> AppResult<()> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            conn.execute(
+                r#"
+                UPDATE tbl_task_run_history 
+                SET ended_at = datetime('now'), duration = ?2, end_type = ?3
+                WHERE id = ?1
+                "#,
+                rusqlite::params![run_id, duration, end_type],
+            )?;
 
-    Ok(())
+            Ok(())
+        })
+        .await
 }
 
 /// 시간 추가
 #[tauri::command]
-pub fn extend_task_time(
+pub async fn extend_task_time(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     input: ExtendTimeInput,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-    let id = uuid::Uuid::new_v4().to_string();
+) -> AppResult<()> {
+    state
+        .run_mut(&app_handle, move |conn| {
+            let id = uuid::Uuid::new_v4().to_string();
+
+            // 히스토리 추가
+            conn.execute(
+                r#"
+                INSERT INTO tbl_task_time_extension (id, task_id, added_minutes, previous_duration, new_duration, reason)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "#,
+                rusqlite::params![
+                    id,
+                    input.task_id,
+                    input.added_minutes,
+                    input.previous_duration,
+                    input.new_duration,
+                    input.reason,
+                ],
+            )?;
 
-    // 히스토리 추가
-    conn.execute(
-        r#"
-        INSERT INTO tbl_task_time_extension (id, task_id, added_minutes, previous_duration, new_duration, reason)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
-        "#,
-        rusqlite::params![
-            id,
-            input.task_id,
-            input.added_minutes,
-            input.previous_duration,
-            input.new_duration,
-            input.reason,
-        ],
-    )
-    .map_err(|e| e.to_string())?;
-
-    // Task의 expected_duration 업데이트
-    conn.execute(
-        "UPDATE tbl_task SET expected_duration = ?2, updated_at = datetime('now') WHERE id = ?1",
-        rusqlite::params![input.task_id, input.new_duration],
-    )
-    .map_err(|e| e.to_string())?;
+            // Task의 expected_duration 업데이트
+            conn.execute(
+                "UPDATE tbl_task SET expected_duration = ?2, updated_at = datetime('now') WHERE id = ?1",
+                rusqlite::params![input.task_id, input.new_duration],
+            )?;
 
+            Ok(())
+        })
+        .await?;
+    crate::tray::refresh_menu(&app_handle);
     Ok(())
 }
 
@@ -783,12 +1468,19 @@ pub struct SidebarCounts {
 
 /// 사이드바 메뉴별 태스크 카운트 조회
 #[tauri::command]
-pub fn get_sidebar_counts(
+pub async fn get_sidebar_counts(
     app_handle: AppHandle,
-    state: State<DbState>,
-) -> Result<SidebarCounts, String> {
-    let conn = get_connection(&app_handle, &state)?;
+    state: State<'_, DbState>,
+) -> AppResult<SidebarCounts> {
+    state
+        .run(&app_handle, move |conn| {
+            sidebar_counts(conn)
+        })
+        .await
+}
 
+/// 사이드바 메뉴별 태스크 카운트 (트레이 메뉴에서도 사용)
+pub fn sidebar_counts(conn: &Connection) -> AppResult<SidebarCounts> {
     // 각 메뉴별 카운트 쿼리
     // inbox: 미완료 작업 전체 (INBOX, IN_PROGRESS, PAUSED) - 화면에 표시되는 전체 수
     let inbox: i64 = conn
@@ -796,24 +1488,21 @@ pub fn get_sidebar_counts(
             "SELECT COUNT(*) FROM tbl_task WHERE status IN ('INBOX', 'IN_PROGRESS', 'PAUSED')",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     let completed: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM tbl_task WHERE status = 'COMPLETED'",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     let starred: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM tbl_task WHERE is_important = 1 AND status NOT IN ('COMPLETED', 'ARCHIVED')",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     // target_date는 ISO 8601 UTC 형식으로 저장되므로 localtime으로 변환하여 비교
     let today: i64 = conn
@@ -821,32 +1510,28 @@ pub fn get_sidebar_counts(
             "SELECT COUNT(*) FROM tbl_task WHERE date(target_date, 'localtime') = date('now', 'localtime') AND status NOT IN ('COMPLETED', 'ARCHIVED')",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     let tomorrow: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM tbl_task WHERE date(target_date, 'localtime') = date('now', 'localtime', '+1 day') AND status NOT IN ('COMPLETED', 'ARCHIVED')",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     let overdue: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM tbl_task WHERE date(target_date, 'localtime') < date('now', 'localtime') AND status NOT IN ('COMPLETED', 'ARCHIVED')",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     let archive: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM tbl_task WHERE status = 'ARCHIVED'",
             [],
             |row| row.get(0),
-        )
-        .map_err(|e| e.to_string())?;
+        )?;
 
     Ok(SidebarCounts {
         inbox,
@@ -859,164 +1544,186 @@ pub fn get_sidebar_counts(
     })
 }
 
-// ============================================================================
-// 설정 커맨드
-// ============================================================================
-
-/// 설정 조회
-#[tauri::command]
-pub fn get_setting(
-    app_handle: AppHandle,
-    state: State<DbState>,
-    key: String,
-) -> Result<Option<String>, String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    let result = conn.query_row(
-        "SELECT value FROM tbl_setting WHERE key = ?1",
-        [&key],
-        |row| row.get::<_, Option<String>>(0),
-    );
-
-    match result {
-        Ok(value) => Ok(value),
-        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-        Err(e) => Err(e.to_string()),
-    }
-}
-
-/// 설정 저장
-#[tauri::command]
-pub fn set_setting(
-    app_handle: AppHandle,
-    state: State<DbState>,
-    key: String,
-    value: String,
-) -> Result<(), String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    conn.execute(
-        r#"
-        INSERT INTO tbl_setting (id, key, value, updated_at)
-        VALUES ('setting_' || ?1, ?1, ?2, datetime('now'))
-        ON CONFLICT(key) DO UPDATE SET value = ?2, updated_at = datetime('now')
-        "#,
-        rusqlite::params![key, value],
-    )
-    .map_err(|e| e.to_string())?;
-
-    Ok(())
-}
-
-/// 전체 설정 조회
-#[tauri::command]
-pub fn get_all_settings(
-    app_handle: AppHandle,
-    state: State<DbState>,
-) -> Result<Vec<Setting>, String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    let mut stmt = conn
-        .prepare("SELECT id, key, value, updated_at FROM tbl_setting ORDER BY key")
-        .map_err(|e| e.to_string())?;
-
-    let settings = stmt
-        .query_map([], |row| {
-            Ok(Setting {
-                id: row.get(0)?,
-                key: row.get(1)?,
-                value: row.get(2)?,
-                updated_at: row.get(3)?,
-            })
-        })
-        .map_err(|e| e.to_string())?
-        .filter_map(|r| r.ok())
-        .collect();
-
-    Ok(settings)
-}
-
 // ============================================================================
 // 테이블 조회 커맨드 (고급)
 // ============================================================================
 
 /// 테이블 목록 조회
 #[tauri::command]
-pub fn list_tables(
+pub async fn list_tables(
     app_handle: AppHandle,
-    state: State<DbState>,
-) -> Result<Vec<String>, String> {
-    let conn = get_connection(&app_handle, &state)?;
-    get_table_list(&conn)
+    state: State<'_, DbState>,
+) -> AppResult<Vec<String>> {
+    state
+        .run(&app_handle, move |conn| {
+            get_table_list(conn)
+        })
+        .await
 }
 
 /// 테이블 내용 조회
 #[tauri::command]
-pub fn query_table(
+pub async fn query_table(
     app_handle: AppHandle,
-    state: State<DbState>,
+    state: State<'_, DbState>,
     table_name: String,
     limit: Option<i64>,
     offset: Option<i64>,
-) -> Result<Vec<TableRow>, String> {
-    let conn = get_connection(&app_handle, &state)?;
-
-    // 테이블명 검증 (SQL 인젝션 방지)
-    if !table_name.starts_with("tbl_") || table_name.contains(|c: char| !c.is_alphanumeric() && c != '_') {
-        return Err("유효하지 않은 테이블명입니다.".to_string());
-    }
+) -> AppResult<Vec<TableRow>> {
+    state
+        .run(&app_handle, move |conn| {
+            // 테이블명 검증 (SQL 인젝션 방지)
+            if !table_name.starts_with("tbl_") || table_name.contains(|c: char| !c.is_alphanumeric() && c != '_') {
+                return Err(AppError::InvalidInput("유효하지 않은 테이블명입니다.".to_string()));
+            }
 
-    let sql = format!(
-        "SELECT * FROM {} LIMIT {} OFFSET {}",
-        table_name,
-        limit.unwrap_or(100),
-        offset.unwrap_or(0)
-    );
-
-    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
-    let columns: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
-
-    let rows: Vec<TableRow> = stmt
-        .query_map([], |row| {
-            let mut values = Vec::new();
-            for i in 0..columns.len() {
-                let val = match row.get_ref(i) {
-                    Ok(rusqlite::types::ValueRef::Null) => serde_json::Value::Null,
-                    Ok(rusqlite::types::ValueRef::Integer(i)) => json!(i),
-                    Ok(rusqlite::types::ValueRef::Real(f)) => json!(f),
-                    Ok(rusqlite::types::ValueRef::Text(t)) => {
-                        json!(String::from_utf8_lossy(t).to_string())
+            let sql = format!(
+                "SELECT * FROM {} LIMIT {} OFFSET {}",
+                table_name,
+                limit.unwrap_or(100),
+                offset.unwrap_or(0)
+            );
+
+            let mut stmt = conn.prepare(&sql)?;
+            let columns: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+
+            let rows: Vec<TableRow> = stmt
+                .query_map([], |row| {
+                    let mut values = Vec::new();
+                    for i in 0..columns.len() {
+                        let val = match row.get_ref(i) {
+                            Ok(rusqlite::types::ValueRef::Null) => serde_json::Value::Null,
+                            Ok(rusqlite::types::ValueRef::Integer(i)) => json!(i),
+                            Ok(rusqlite::types::ValueRef::Real(f)) => json!(f),
+                            Ok(rusqlite::types::ValueRef::Text(t)) => {
+                                json!(String::from_utf8_lossy(t).to_string())
+                            }
+                            Ok(rusqlite::types::ValueRef::Blob(b)) => json!(format!("[blob {} bytes]", b.len())),
+                            Err(_) => serde_json::Value::Null,
+                        };
+                        values.push(val);
                     }
-                    Ok(rusqlite::types::ValueRef::Blob(b)) => json!(format!("[blob {} bytes]", b.len())),
-                    Err(_) => serde_json::Value::Null,
-                };
-                values.push(val);
-            }
-            Ok(TableRow {
-                columns: columns.clone(),
-                values,
-            })
+                    Ok(TableRow {
+                        columns: columns.clone(),
+                        values,
+                    })
+                })?
+                .filter_map(|r| r.ok())
+                .collect();
+
+            Ok(rows)
         })
-        .map_err(|e| e.to_string())?
-        .filter_map(|r| r.ok())
-        .collect();
-
-    Ok(rows)
+        .await
 }
 
 // ============================================================================
 // 내부 헬퍼 함수들
 // ============================================================================
 
-/// 앱 시작 시 모든 IN_PROGRESS 작업을 PAUSED로 변경
-fn reset_all_tasks_to_paused(conn: &Connection) -> Result<(), String> {
-    conn.execute(
-        "UPDATE tbl_task SET status = 'PAUSED' WHERE status = 'IN_PROGRESS'",
-        [],
-    ).map_err(|e| e.to_string())?;
+/// 앱 시작 시 실행 중이던 작업 복구
+/// `timer_resume_on_launch` 설정에 따라 타이머를 이어서 실행하거나 일시정지 처리
+/// (같은 DB에 대해서는 프로세스당 한 번만 실행하여 다른 창의 get_db_status 호출로 타이머가 멈추지 않도록 함)
+async fn recover_running_tasks(
+    app_handle: &AppHandle,
+    state: &DbState,
+    path: &Path,
+) -> AppResult<()> {
+    // 복구 중 다른 창의 호출이 다시 복구하지 않도록 먼저 표시 (실패 시 해제)
+    let previous = {
+        let mut recovered_path = state.recovered_path.lock()?;
+        if recovered_path.as_deref() == Some(path) {
+            return Ok(());
+        }
+        recovered_path.replace(path.to_path_buf())
+    };
+
+    let result = state
+        .worker
+        .run(path.to_path_buf(), |conn| {
+            let resume = settings::get_bool(conn, "timer_resume_on_launch");
+            let resumed = timer_store::recover_running_tasks(conn, resume)?;
+            log::info!("[Recovery] resume={}, resumed timers={}", resume, resumed.len());
+            Ok(resumed)
+        })
+        .await;
+
+    match result {
+        Ok(resumed) => {
+            crate::timer::restore_timers(app_handle, resumed);
+            crate::tray::refresh_menu(app_handle);
+            Ok(())
+        }
+        Err(e) => {
+            *state.recovered_path.lock()? = previous;
+            Err(e)
+        }
+    }
+}
+
+/// 암호화된 DB 잠금 해제 (암호를 확인한 뒤 작업 스레드에 등록)
+///
+/// 새 DB(파일이 없거나 비어 있음)에 암호를 주면 처음부터 암호화된 DB로 만든다.
+/// 평문 DB에 준 암호는 무시한다.
+fn unlock(state: &DbState, path: &Path, passphrase: Option<String>) -> AppResult<()> {
+    let Some(passphrase) = passphrase.filter(|p| !p.is_empty()) else {
+        return Ok(());
+    };
+    if crypto::is_encrypted(path) {
+        crypto::check_key(path, &passphrase)?;
+    } else if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(());
+    }
+    state.worker.set_passphrase(path, Some(passphrase));
     Ok(())
 }
 
+/// 앱 시작 시 설정된 DB 준비 (잠금 → 마이그레이션 → 타이머 복구)
+///
+/// 다른 인스턴스가 잠금을 보유하고 있으면 오류 대신 읽기 전용으로 연다.
+/// 읽기 전용일 때는 파일을 바꾸지 않도록 마이그레이션과 타이머 복구를 건너뛴다.
+async fn prepare_db(app_handle: &AppHandle, state: &DbState, path: &Path) -> AppResult<()> {
+    match lock::acquire(path, false) {
+        Ok(()) => {
+            *state.locked_path.lock()? = Some(path.to_path_buf());
+            if state.worker.is_read_only(path) {
+                state.worker.set_read_only_path(None);
+            }
+        }
+        Err(e @ (AppError::DbLocked(_) | AppError::DbLockStale(_))) => {
+            log::warn!("[DB] Opening read-only: {}", e);
+            state.worker.set_read_only_path(Some(path.to_path_buf()));
+            {
+                let mut locked_path = state.locked_path.lock()?;
+                if locked_path.as_deref() == Some(path) {
+                    *locked_path = None;
+                }
+            }
+            return state
+                .worker
+                .run(path.to_path_buf(), |conn| check_readable_schema(conn))
+                .await;
+        }
+        Err(e) => return Err(e),
+    }
+
+    migrate(app_handle, state, path).await?;
+    recover_running_tasks(app_handle, state, path).await
+}
+
+/// 마이그레이션 실행 (작업 스레드의 연결 사용)
+/// 적용할 마이그레이션이 있으면 먼저 백업하고, 백업에 실패하면 마이그레이션하지 않음
+pub(crate) async fn migrate(app_handle: &AppHandle, state: &DbState, path: &Path) -> AppResult<()> {
+    let backup_dir = backup::backup_dir(app_handle)?;
+    let db_path = path.to_path_buf();
+    state
+        .worker
+        .run(path.to_path_buf(), move |conn| {
+            backup::backup_before_migration(conn, &db_path, &backup_dir)?;
+            run_migrations(conn)
+        })
+        .await
+}
+
 fn map_task_row(row: &rusqlite::Row) -> rusqlite::Result<Task> {
     Ok(Task {
         id: row.get("id")?,
@@ -1045,24 +1752,21 @@ fn map_task_row(row: &rusqlite::Row) -> rusqlite::Result<Task> {
     })
 }
 
-fn load_task_tags(conn: &Connection, task_id: &str) -> Result<Vec<String>, String> {
+fn load_task_tags(conn: &Connection, task_id: &str) -> AppResult<Vec<String>> {
     let mut stmt = conn
-        .prepare("SELECT tag FROM tbl_task_tag WHERE task_id = ?1 ORDER BY created_at")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached("SELECT tag FROM tbl_task_tag WHERE task_id = ?1 ORDER BY created_at")?;
 
     let tags = stmt
-        .query_map([task_id], |row| row.get(0))
-        .map_err(|e| e.to_string())?
+        .query_map([task_id], |row| row.get(0))?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(tags)
 }
 
-fn load_task_memos(conn: &Connection, task_id: &str) -> Result<Vec<TaskMemo>, String> {
+fn load_task_memos(conn: &Connection, task_id: &str) -> AppResult<Vec<TaskMemo>> {
     let mut stmt = conn
-        .prepare("SELECT id, task_id, content, created_at FROM tbl_task_memo WHERE task_id = ?1 ORDER BY created_at DESC")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached("SELECT id, task_id, content, created_at FROM tbl_task_memo WHERE task_id = ?1 ORDER BY created_at DESC")?;
 
     let memos = stmt
         .query_map([task_id], |row| {
@@ -1072,18 +1776,16 @@ fn load_task_memos(conn: &Connection, task_id: &str) -> Result<Vec<TaskMemo>, St
                 content: row.get(2)?,
                 created_at: row.get(3)?,
             })
-        })
-        .map_err(|e| e.to_string())?
+        })?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(memos)
 }
 
-fn load_task_notes(conn: &Connection, task_id: &str) -> Result<Vec<TaskNote>, String> {
+fn load_task_notes(conn: &Connection, task_id: &str) -> AppResult<Vec<TaskNote>> {
     let mut stmt = conn
-        .prepare("SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE task_id = ?1 ORDER BY created_at DESC")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached("SELECT id, task_id, title, content, created_at, updated_at FROM tbl_task_note WHERE task_id = ?1 ORDER BY created_at DESC")?;
 
     let notes = stmt
         .query_map([task_id], |row| {
@@ -1095,18 +1797,23 @@ fn load_task_notes(conn: &Connection, task_id: &str) -> Result<Vec<TaskNote>, St
                 created_at: row.get(4)?,
                 updated_at: row.get(5)?,
             })
-        })
-        .map_err(|e| e.to_string())?
+        })?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(notes)
 }
 
-fn load_task_run_history(conn: &Connection, task_id: &str) -> Result<Vec<TaskRunHistory>, String> {
+fn load_task_run_history(conn: &Connection, task_id: &str) -> AppResult<Vec<TaskRunHistory>> {
+    // 읽기 전용으로 연 이전 스키마 DB는 나중에 추가된 컬럼 대신 기본값 사용
+    let phase = if has_column(conn, "tbl_task_run_history", "phase")? { "phase" } else { "'focus'" };
+    let overtime_seconds = if has_column(conn, "tbl_task_run_history", "overtime_seconds")? {
+        "overtime_seconds"
+    } else {
+        "0"
+    };
     let mut stmt = conn
-        .prepare("SELECT id, task_id, started_at, ended_at, duration, end_type FROM tbl_task_run_history WHERE task_id = ?1 ORDER BY started_at DESC")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached(&format!("SELECT id, task_id, started_at, ended_at, duration, end_type, {}, {} FROM tbl_task_run_history WHERE task_id = ?1 ORDER BY started_at DESC", phase, overtime_seconds))?;
 
     let history = stmt
         .query_map([task_id], |row| {
@@ -1117,19 +1824,19 @@ fn load_task_run_history(conn: &Connection, task_id: &str) -> Result<Vec<TaskRun
                 ended_at: row.get(3)?,
                 duration: row.get(4)?,
                 end_type: row.get(5)?,
+                phase: row.get(6)?,
+                overtime_seconds: row.get(7)?,
             })
-        })
-        .map_err(|e| e.to_string())?
+        })?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(history)
 }
 
-fn load_task_time_extensions(conn: &Connection, task_id: &str) -> Result<Vec<TaskTimeExtension>, String> {
+fn load_task_time_extensions(conn: &Connection, task_id: &str) -> AppResult<Vec<TaskTimeExtension>> {
     let mut stmt = conn
-        .prepare("SELECT id, task_id, added_minutes, previous_duration, new_duration, reason, created_at FROM tbl_task_time_extension WHERE task_id = ?1 ORDER BY created_at DESC")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached("SELECT id, task_id, added_minutes, previous_duration, new_duration, reason, created_at FROM tbl_task_time_extension WHERE task_id = ?1 ORDER BY created_at DESC")?;
 
     let extensions = stmt
         .query_map([task_id], |row| {
@@ -1142,18 +1849,16 @@ fn load_task_time_extensions(conn: &Connection, task_id: &str) -> Result<Vec<Tas
                 reason: row.get(5)?,
                 created_at: row.get(6)?,
             })
-        })
-        .map_err(|e| e.to_string())?
+        })?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(extensions)
 }
 
-fn load_task_action_history(conn: &Connection, task_id: &str) -> Result<Vec<TaskActionHistory>, String> {
+fn load_task_action_history(conn: &Connection, task_id: &str) -> AppResult<Vec<TaskActionHistory>> {
     let mut stmt = conn
-        .prepare("SELECT id, task_id, action_type, previous_status, new_status, metadata, created_at FROM tbl_task_action_history WHERE task_id = ?1 ORDER BY created_at DESC")
-        .map_err(|e| e.to_string())?;
+        .prepare_cached("SELECT id, task_id, action_type, previous_status, new_status, metadata, created_at FROM tbl_task_action_history WHERE task_id = ?1 ORDER BY created_at DESC")?;
 
     let history = stmt
         .query_map([task_id], |row| {
@@ -1166,23 +1871,34 @@ fn load_task_action_history(conn: &Connection, task_id: &str) -> Result<Vec<Task
                 metadata: row.get(5)?,
                 created_at: row.get(6)?,
             })
-        })
-        .map_err(|e| e.to_string())?
+        })?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(history)
 }
 
+/// 상태 변경에 해당하는 액션 히스토리 타입
+pub(super) fn status_action_type(status: &str) -> &'static str {
+    match status {
+        "IN_PROGRESS" => "STARTED",
+        "PAUSED" => "PAUSED",
+        "COMPLETED" => "COMPLETED",
+        "ARCHIVED" => "ARCHIVED",
+        "INBOX" => "RESTORED",
+        _ => "STATUS_CHANGED",
+    }
+}
+
 /// 액션 히스토리 기록 내부 함수
-fn add_action_history_internal(
+pub(super) fn add_action_history_internal(
     conn: &Connection,
     task_id: &str,
     action_type: &str,
     previous_status: Option<&str>,
     new_status: Option<&str>,
     metadata: Option<&str>,
-) -> Result<(), String> {
+) -> AppResult<()> {
     let id = uuid::Uuid::new_v4().to_string();
     conn.execute(
         r#"
@@ -1190,8 +1906,7 @@ fn add_action_history_internal(
         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
         "#,
         rusqlite::params![id, task_id, action_type, previous_status, new_status, metadata],
-    )
-    .map_err(|e| e.to_string())?;
+    )?;
     Ok(())
 }
 
@@ -0,0 +1,129 @@
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection, ErrorCode, OpenFlags};
+
+use super::maintenance::table_stats_in;
+use crate::error::{AppError, AppResult};
+
+/// 평문 SQLite 파일 헤더
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// 암호화된 DB인지 확인 (파일 헤더가 평문 SQLite 헤더가 아니면 암호화된 것으로 봄)
+/// 파일이 없거나 비어 있는 새 DB는 false
+pub fn is_encrypted(path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    match File::open(path).and_then(|mut file| file.read_exact(&mut header)) {
+        Ok(()) => &header != SQLITE_HEADER,
+        Err(_) => false,
+    }
+}
+
+/// 암호화/암호 변경 중 만드는 임시 파일 경로 ({db}.crypt-tmp)
+pub fn temp_path(db_path: &Path) -> PathBuf {
+    let mut path = OsString::from(db_path.as_os_str());
+    path.push(".crypt-tmp");
+    PathBuf::from(path)
+}
+
+/// 연결에 암호 적용 (연결 직후 다른 쿼리보다 먼저 호출)
+/// 암호가 틀리면 SQLCipher가 "file is not a database"를 반환하므로 `WRONG_PASSPHRASE`로 변환
+pub fn apply_key(conn: &Connection, passphrase: &str) -> AppResult<()> {
+    conn.pragma_update(None, "key", passphrase)?;
+    check_readable(conn, "main")
+}
+
+/// 연결을 유지하지 않고 암호만 확인 (읽기 전용으로 열어 파일을 바꾸지 않음)
+pub fn check_key(path: &Path, passphrase: &str) -> AppResult<()> {
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    apply_key(&conn, passphrase)
+}
+
+fn check_readable(conn: &Connection, schema: &str) -> AppResult<()> {
+    let result = conn.query_row(
+        &format!("SELECT COUNT(*) FROM {}.sqlite_master", schema),
+        [],
+        |row| row.get::<_, i64>(0),
+    );
+    match result {
+        Ok(_) => Ok(()),
+        Err(rusqlite::Error::SqliteFailure(error, _)) if error.code == ErrorCode::NotADatabase => {
+            Err(AppError::WrongPassphrase)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 현재 DB를 다른 파일로 내보내고 검증 (sqlcipher_export)
+///
+/// - `None`: 현재 DB와 같은 키 (평문 DB는 평문, 백업/이동용)
+/// - `Some("")`: 평문 (복호화 내보내기)
+/// - `Some(passphrase)`: 새 암호로 암호화
+///
+/// online backup API는 암호화 설정이 다른 DB 사이에서 사용할 수 없어 이 함수를 사용한다.
+pub fn export(conn: &Connection, target: &Path, passphrase: Option<&str>) -> AppResult<()> {
+    let target = target.to_string_lossy().to_string();
+    match passphrase {
+        Some(passphrase) => conn.execute(
+            "ATTACH DATABASE ?1 AS export KEY ?2",
+            params![target, passphrase],
+        )?,
+        None => conn.execute("ATTACH DATABASE ?1 AS export", [&target])?,
+    };
+
+    let result = export_attached(conn, "main");
+    conn.execute("DETACH DATABASE export", [])?;
+    result
+}
+
+/// `from` 스키마를 `export`로 내보낸 뒤 무결성과 테이블별 행 수 검증
+fn export_attached(conn: &Connection, from: &str) -> AppResult<()> {
+    conn.query_row("SELECT sqlcipher_export('export', ?1)", [from], |_| Ok(()))?;
+    copy_user_version(conn, from, "export")?;
+
+    let integrity: String =
+        conn.query_row("PRAGMA export.integrity_check", [], |row| row.get(0))?;
+    if integrity != "ok" || table_stats_in(conn, from)? != table_stats_in(conn, "export")? {
+        log::warn!("[Crypto] Export verification failed: {}", integrity);
+        return Err(AppError::Internal(
+            "내보낸 DB 검증에 실패했습니다.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 암호화된 백업 파일을 현재 DB와 같은 키의 새 파일로 내보내고 검증 (백업 복원용)
+///
+/// 백업 파일은 현재 DB와 같은 암호로 암호화되어 있어야 한다 (다르면 `WRONG_PASSPHRASE`).
+/// 현재 DB는 건드리지 않으며, 만든 파일은 호출한 쪽에서 연결을 닫고 기존 파일과 교체한다.
+pub fn export_backup(conn: &Connection, source: &Path, target: &Path) -> AppResult<()> {
+    conn.execute(
+        "ATTACH DATABASE ?1 AS source",
+        [source.to_string_lossy().to_string()],
+    )?;
+    let result = check_readable(conn, "source").and_then(|()| {
+        conn.execute(
+            "ATTACH DATABASE ?1 AS export",
+            [target.to_string_lossy().to_string()],
+        )?;
+        let result = export_attached(conn, "source");
+        conn.execute("DETACH DATABASE export", [])?;
+        result
+    });
+    conn.execute("DETACH DATABASE source", [])?;
+    result
+}
+
+/// 스키마 버전(user_version) 복사 (마이그레이션 기준)
+fn copy_user_version(conn: &Connection, from: &str, to: &str) -> AppResult<()> {
+    let version: i64 = conn.query_row(&format!("PRAGMA {}.user_version", from), [], |row| {
+        row.get(0)
+    })?;
+    conn.execute_batch(&format!("PRAGMA {}.user_version = {}", to, version))?;
+    Ok(())
+}
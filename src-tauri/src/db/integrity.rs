@@ -0,0 +1,228 @@
+use rusqlite::Connection;
+
+use super::migration::has_column;
+use super::models::{DbCheckReport, DbIssue, DbIssueKind, TaskPriority, TaskStatus};
+use crate::error::AppResult;
+
+/// tbl_task를 참조하는 테이블 (테이블, 행 식별 컬럼)
+const TASK_CHILD_TABLES: &[(&str, &str)] = &[
+    ("tbl_task_tag", "id"),
+    ("tbl_task_memo", "id"),
+    ("tbl_task_note", "id"),
+    ("tbl_task_run_history", "id"),
+    ("tbl_task_time_extension", "id"),
+    ("tbl_task_action_history", "id"),
+    ("tbl_timer_checkpoint", "task_id"),
+];
+
+/// 음수가 될 수 없는 컬럼 (테이블, 행 식별 컬럼, 컬럼, 복구 값)
+/// 읽기 전용으로 연 이전 스키마 DB에 없는 테이블/컬럼은 점검하지 않음
+const NON_NEGATIVE_COLUMNS: &[(&str, &str, &str, &str)] = &[
+    ("tbl_task", "id", "total_time_spent", "0"),
+    ("tbl_task", "id", "remaining_time_seconds", "NULL"),
+    ("tbl_task_run_history", "id", "duration", "0"),
+    ("tbl_task_run_history", "id", "overtime_seconds", "0"),
+    ("tbl_timer_checkpoint", "task_id", "remaining_seconds", "0"),
+];
+
+const VALID_STATUSES: &str = "'INBOX', 'IN_PROGRESS', 'PAUSED', 'COMPLETED', 'ARCHIVED'";
+const VALID_PRIORITIES: &str = "'LOW', 'MEDIUM', 'HIGH'";
+
+/// DB 점검 (읽기 전용)
+///
+/// - `PRAGMA integrity_check`: 파일 손상 (복구 불가, 백업 복원 필요)
+/// - `PRAGMA foreign_key_check`: task 하위 테이블 외의 외래 키 위반
+/// - task가 없는 하위 행 (외래 키가 적용되지 않던 시기에 남은 행)
+/// - 음수 시간, 앱이 INBOX/MEDIUM으로 대신 표시하는 상태/우선순위 문자열
+pub fn check_db(conn: &Connection) -> AppResult<DbCheckReport> {
+    let integrity_errors: Vec<String> = conn
+        .prepare("PRAGMA integrity_check")?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|message| message != "ok")
+        .collect();
+
+    let mut issues = Vec::new();
+    check_foreign_keys(conn, &mut issues)?;
+    check_orphans(conn, &mut issues)?;
+    check_negative_values(conn, &mut issues)?;
+    check_enum_values(conn, &mut issues)?;
+
+    Ok(DbCheckReport {
+        ok: integrity_errors.is_empty() && issues.is_empty(),
+        integrity_errors,
+        issues,
+        repaired_rows: 0,
+        backup_file: None,
+    })
+}
+
+fn check_foreign_keys(conn: &Connection, issues: &mut Vec<DbIssue>) -> AppResult<()> {
+    let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+    let violations = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (table, rowid, parent) in violations {
+        // task 하위 테이블은 고아 행 점검에서 행 id로 보고
+        if parent == "tbl_task" && TASK_CHILD_TABLES.iter().any(|(t, _)| *t == table) {
+            continue;
+        }
+        issues.push(DbIssue {
+            kind: DbIssueKind::ForeignKey,
+            table,
+            row_id: rowid.map(|r| r.to_string()).unwrap_or_default(),
+            column: None,
+            value: Some(parent),
+            repairable: false,
+        });
+    }
+    Ok(())
+}
+
+fn check_orphans(conn: &Connection, issues: &mut Vec<DbIssue>) -> AppResult<()> {
+    for (table, key) in TASK_CHILD_TABLES {
+        if !has_column(conn, table, "task_id")? {
+            continue;
+        }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {key}, task_id FROM {table} WHERE task_id NOT IN (SELECT id FROM tbl_task)"
+        ))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        issues.extend(rows.into_iter().map(|(row_id, task_id)| DbIssue {
+            kind: DbIssueKind::OrphanRow,
+            table: table.to_string(),
+            row_id,
+            column: Some("task_id".to_string()),
+            value: Some(task_id),
+            repairable: true,
+        }));
+    }
+    Ok(())
+}
+
+fn check_negative_values(conn: &Connection, issues: &mut Vec<DbIssue>) -> AppResult<()> {
+    for (table, key, column, _) in NON_NEGATIVE_COLUMNS {
+        if !has_column(conn, table, column)? {
+            continue;
+        }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {key}, {column} FROM {table} WHERE {column} < 0"
+        ))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        issues.extend(rows.into_iter().map(|(row_id, value)| DbIssue {
+            kind: DbIssueKind::NegativeValue,
+            table: table.to_string(),
+            row_id,
+            column: Some(column.to_string()),
+            value: Some(value.to_string()),
+            repairable: true,
+        }));
+    }
+    Ok(())
+}
+
+fn check_enum_values(conn: &Connection, issues: &mut Vec<DbIssue>) -> AppResult<()> {
+    for (kind, column, valid) in [
+        (DbIssueKind::InvalidStatus, "status", VALID_STATUSES),
+        (DbIssueKind::InvalidPriority, "priority", VALID_PRIORITIES),
+    ] {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, {column} FROM tbl_task WHERE {column} NOT IN ({valid})"
+        ))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        issues.extend(rows.into_iter().map(|(row_id, value)| DbIssue {
+            kind: kind.clone(),
+            table: "tbl_task".to_string(),
+            row_id,
+            column: Some(column.to_string()),
+            value: Some(value),
+            repairable: true,
+        }));
+    }
+    Ok(())
+}
+
+/// 점검 결과의 복구 가능한 문제 수정 (하나의 트랜잭션)
+///
+/// 고아 행은 삭제하고, 음수 값은 기본값으로, 상태/우선순위는 앱이 표시하던 값으로 맞춘다.
+/// 수정/삭제한 행 수를 반환한다.
+pub fn repair_db(conn: &Connection, report: &DbCheckReport) -> AppResult<usize> {
+    let tx = conn.unchecked_transaction()?;
+    let mut repaired = 0;
+
+    for (table, _) in TASK_CHILD_TABLES {
+        repaired += tx.execute(
+            &format!("DELETE FROM {table} WHERE task_id NOT IN (SELECT id FROM tbl_task)"),
+            [],
+        )?;
+    }
+
+    for (table, _, column, replacement) in NON_NEGATIVE_COLUMNS {
+        repaired += tx.execute(
+            &format!("UPDATE {table} SET {column} = {replacement} WHERE {column} < 0"),
+            [],
+        )?;
+    }
+
+    for issue in &report.issues {
+        let (column, normalized) = match (&issue.kind, &issue.value) {
+            (DbIssueKind::InvalidStatus, Some(value)) => {
+                ("status", TaskStatus::from(value.as_str()).to_string())
+            }
+            (DbIssueKind::InvalidPriority, Some(value)) => {
+                ("priority", TaskPriority::from(value.as_str()).to_string())
+            }
+            _ => continue,
+        };
+        repaired += tx.execute(
+            &format!("UPDATE tbl_task SET {column} = ?1 WHERE id = ?2"),
+            [&normalized, &issue.row_id],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(repaired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::SCHEMA_SQL;
+
+    #[test]
+    fn check_skips_columns_missing_from_old_schema() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA_SQL).unwrap();
+        conn.execute_batch(
+            "ALTER TABLE tbl_task_run_history DROP COLUMN overtime_seconds;
+             DROP TABLE tbl_timer_checkpoint;",
+        )
+        .unwrap();
+
+        let report = check_db(&conn).unwrap();
+        assert!(report.ok);
+    }
+}
@@ -0,0 +1,273 @@
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use chrono::{Duration as ChronoDuration, NaiveDateTime, Utc};
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use super::commands::DbState;
+use super::models::DbLockInfo;
+use crate::error::{AppError, AppResult};
+
+/// 하트비트 갱신 주기
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// 하트비트가 이 시간 이상 갱신되지 않으면 응답 없는 잠금으로 판단 (초)
+const STALE_AFTER_SECS: i64 = 60;
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 이 프로세스의 인스턴스 id (같은 pid가 다른 기기에 있어도 구분)
+fn instance_id() -> &'static str {
+    static ID: OnceLock<String> = OnceLock::new();
+    ID.get_or_init(|| uuid::Uuid::new_v4().to_string())
+}
+
+fn host_name() -> &'static str {
+    static HOST: OnceLock<String> = OnceLock::new();
+    HOST.get_or_init(|| {
+        Command::new("hostname")
+            .output()
+            .ok()
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .unwrap_or_else(|| "unknown".to_string())
+    })
+}
+
+fn now() -> String {
+    Utc::now().format(DATETIME_FORMAT).to_string()
+}
+
+/// 잠금 파일 경로 ({db}.lock)
+fn lock_path(db_path: &Path) -> PathBuf {
+    let mut path = OsString::from(db_path.as_os_str());
+    path.push(".lock");
+    PathBuf::from(path)
+}
+
+fn read_lock(db_path: &Path) -> Option<DbLockInfo> {
+    let data = fs::read_to_string(lock_path(db_path)).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+fn write_lock(db_path: &Path, info: &DbLockInfo) -> AppResult<()> {
+    let path = lock_path(db_path);
+    let mut temp_path = path.clone().into_os_string();
+    temp_path.push(".tmp");
+    fs::write(&temp_path, serde_json::to_vec_pretty(info)?)?;
+    fs::rename(&temp_path, &path)?;
+    Ok(())
+}
+
+/// 잠금 파일이 없을 때만 새로 만듦 (다른 인스턴스가 먼저 만들었으면 false)
+fn create_lock(db_path: &Path, info: &DbLockInfo) -> AppResult<bool> {
+    let mut file = match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(lock_path(db_path))
+    {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    file.write_all(&serde_json::to_vec_pretty(info)?)?;
+    Ok(true)
+}
+
+fn new_lock(acquired_at: String) -> DbLockInfo {
+    DbLockInfo {
+        host: host_name().to_string(),
+        pid: std::process::id(),
+        instance_id: instance_id().to_string(),
+        acquired_at,
+        heartbeat_at: now(),
+    }
+}
+
+fn is_own(info: &DbLockInfo) -> bool {
+    info.instance_id == instance_id()
+}
+
+fn is_stale(info: &DbLockInfo) -> bool {
+    match NaiveDateTime::parse_from_str(&info.heartbeat_at, DATETIME_FORMAT) {
+        Ok(heartbeat_at) => {
+            Utc::now().naive_utc() - heartbeat_at > ChronoDuration::seconds(STALE_AFTER_SECS)
+        }
+        Err(_) => true,
+    }
+}
+
+fn describe(info: &DbLockInfo) -> String {
+    format!(
+        "{} (pid {}), heartbeat {} UTC",
+        info.host, info.pid, info.heartbeat_at
+    )
+}
+
+/// 잠금 획득 (이미 보유 중이면 하트비트 갱신)
+///
+/// 다른 인스턴스가 사용 중이면 `DB_LOCKED`, 하트비트가 끊긴 잠금이면 `DB_LOCK_STALE`.
+/// 응답 없는 잠금은 사용자 확인 후 `take_over`로 가져올 수 있다.
+pub fn acquire(db_path: &Path, take_over: bool) -> AppResult<()> {
+    match read_lock(db_path) {
+        Some(info) if is_own(&info) => write_lock(db_path, &new_lock(info.acquired_at)),
+        Some(info) if !is_stale(&info) => Err(AppError::DbLocked(describe(&info))),
+        Some(info) if !take_over => Err(AppError::DbLockStale(describe(&info))),
+        Some(info) => {
+            log::warn!("[Lock] Taking over stale lock: {}", describe(&info));
+            take_over_lock(db_path)
+        }
+        None => {
+            // 확인과 생성 사이에 다른 인스턴스가 만들 수 있으므로 없을 때만 생성
+            if create_lock(db_path, &new_lock(now()))? {
+                return Ok(());
+            }
+            match read_lock(db_path) {
+                Some(info) if is_own(&info) => Ok(()),
+                Some(info) => Err(AppError::DbLocked(describe(&info))),
+                // 읽을 수 없는 잠금 파일 (손상 등)은 응답 없는 잠금과 같이 확인 후 가져옴
+                None if take_over => take_over_lock(db_path),
+                None => Err(AppError::DbLockStale("알 수 없는 잠금 파일".to_string())),
+            }
+        }
+    }
+}
+
+/// 응답 없는 잠금 가져오기
+///
+/// 여러 인스턴스가 동시에 가져오면 마지막으로 쓴 쪽만 남으므로 쓴 뒤 다시 읽어 확인한다.
+fn take_over_lock(db_path: &Path) -> AppResult<()> {
+    write_lock(db_path, &new_lock(now()))?;
+    match read_lock(db_path) {
+        Some(info) if is_own(&info) => Ok(()),
+        Some(info) => Err(AppError::DbLocked(describe(&info))),
+        None => Err(AppError::DbLocked("알 수 없는 잠금 파일".to_string())),
+    }
+}
+
+/// 잠금 해제 (이 인스턴스의 잠금일 때만 삭제)
+pub fn release(db_path: &Path) {
+    if read_lock(db_path).is_some_and(|info| is_own(&info)) {
+        match fs::remove_file(lock_path(db_path)) {
+            Ok(()) => log::info!("[Lock] Released: {}", db_path.display()),
+            Err(e) => log::warn!("[Lock] Failed to release {}: {}", db_path.display(), e),
+        }
+    }
+}
+
+/// 다른 인스턴스가 보유한 잠금 (잠금 정보, 응답 없음 여부)
+pub fn holder(db_path: &Path) -> Option<(DbLockInfo, bool)> {
+    read_lock(db_path).filter(|info| !is_own(info)).map(|info| {
+        let stale = is_stale(&info);
+        (info, stale)
+    })
+}
+
+/// 종료 시 보유한 잠금 해제
+pub fn release_held(app_handle: &AppHandle) {
+    let state = app_handle.state::<DbState>();
+    let held = state.locked_path.lock().ok().and_then(|mut p| p.take());
+    if let Some(path) = held {
+        release(&path);
+    }
+}
+
+/// 잠금 하트비트 루프
+///
+/// 보유한 잠금의 하트비트를 갱신한다. 다른 인스턴스가 잠금을 가져갔으면
+/// 해당 DB를 읽기 전용으로 바꾸고 `db-lock-lost`를 보낸다.
+pub async fn run_lock_heartbeat(app_handle: AppHandle) {
+    let mut ticker = interval(HEARTBEAT_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        let state = app_handle.state::<DbState>();
+        let Some(db_path) = state.locked_path.lock().ok().and_then(|p| p.clone()) else {
+            continue;
+        };
+
+        // 잠금 파일이 지워졌으면(동기화 등) acquire가 다시 만듦
+        match acquire(&db_path, false) {
+            Ok(()) => {}
+            Err(e @ (AppError::DbLocked(_) | AppError::DbLockStale(_))) => {
+                log::warn!("[Lock] Lock lost: {}", e);
+                if let Ok(mut locked_path) = state.locked_path.lock() {
+                    *locked_path = None;
+                }
+                state.worker.set_read_only_path(Some(db_path.clone()));
+                let _ = app_handle.emit("db-lock-lost", db_path.to_string_lossy().to_string());
+            }
+            Err(e) => log::warn!("[Lock] Failed to update heartbeat: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock_with_heartbeat(heartbeat_at: String) -> DbLockInfo {
+        DbLockInfo {
+            heartbeat_at,
+            ..new_lock(now())
+        }
+    }
+
+    fn seconds_ago(secs: i64) -> String {
+        (Utc::now() - ChronoDuration::seconds(secs))
+            .format(DATETIME_FORMAT)
+            .to_string()
+    }
+
+    #[test]
+    fn recent_heartbeat_is_not_stale() {
+        assert!(!is_stale(&lock_with_heartbeat(now())));
+        assert!(!is_stale(&lock_with_heartbeat(seconds_ago(
+            STALE_AFTER_SECS - 5
+        ))));
+    }
+
+    #[test]
+    fn old_heartbeat_is_stale() {
+        assert!(is_stale(&lock_with_heartbeat(seconds_ago(
+            STALE_AFTER_SECS + 5
+        ))));
+    }
+
+    #[test]
+    fn create_lock_does_not_overwrite_existing_lock() {
+        let db_path = std::env::temp_dir().join(format!("mirumi-test-{}.db", uuid::Uuid::new_v4()));
+        let other = DbLockInfo {
+            instance_id: "other".to_string(),
+            ..new_lock(now())
+        };
+
+        assert!(create_lock(&db_path, &other).unwrap());
+        assert!(!create_lock(&db_path, &new_lock(now())).unwrap());
+        assert_eq!(read_lock(&db_path).unwrap().instance_id, "other");
+        assert!(matches!(
+            acquire(&db_path, false),
+            Err(AppError::DbLocked(_))
+        ));
+
+        let _ = fs::remove_file(lock_path(&db_path));
+    }
+
+    #[test]
+    fn unparsable_heartbeat_is_stale() {
+        assert!(is_stale(&lock_with_heartbeat(String::new())));
+        assert!(is_stale(&lock_with_heartbeat(
+            "2025-12-16T14:30:52Z".to_string()
+        )));
+    }
+}
@@ -0,0 +1,141 @@
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, DatabaseName};
+
+use super::models::{DbStats, TableStat};
+use crate::error::{AppError, AppResult};
+
+/// WAL 파일 경로 ({db}-wal)
+pub fn wal_path(db_path: &Path) -> PathBuf {
+    let mut path = OsString::from(db_path.as_os_str());
+    path.push("-wal");
+    PathBuf::from(path)
+}
+
+/// DB 파일 + WAL 파일 크기
+pub fn total_size_bytes(db_path: &Path) -> u64 {
+    [db_path.to_path_buf(), wal_path(db_path)]
+        .iter()
+        .filter_map(|p| fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn pragma_u64(conn: &Connection, name: &str) -> AppResult<u64> {
+    Ok(conn.query_row(&format!("PRAGMA {}", name), [], |row| row.get::<_, i64>(0))? as u64)
+}
+
+/// 테이블별 행 수
+fn table_stats(conn: &Connection) -> AppResult<Vec<TableStat>> {
+    table_stats_in(conn, "main")
+}
+
+/// 연결된 DB(main, ATTACH한 DB)의 테이블별 행 수
+pub(crate) fn table_stats_in(conn: &Connection, schema: &str) -> AppResult<Vec<TableStat>> {
+    let names: Vec<String> = conn
+        .prepare(&format!(
+            "SELECT name FROM {}.sqlite_master WHERE type='table' AND name LIKE 'tbl_%' ORDER BY name",
+            schema
+        ))?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let mut table_stats = Vec::new();
+    for name in names {
+        let row_count: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}.{}", schema, name),
+            [],
+            |row| row.get(0),
+        )?;
+        table_stats.push(TableStat {
+            name,
+            row_count: row_count as u64,
+        });
+    }
+    Ok(table_stats)
+}
+
+/// 테이블별 행 수와 페이지 통계
+pub fn collect_stats(conn: &Connection, db_path: &Path) -> AppResult<DbStats> {
+    Ok(DbStats {
+        table_stats: table_stats(conn)?,
+        page_size: pragma_u64(conn, "page_size")?,
+        page_count: pragma_u64(conn, "page_count")?,
+        freelist_count: pragma_u64(conn, "freelist_count")?,
+        wal_size_bytes: fs::metadata(wal_path(db_path))
+            .map(|m| m.len())
+            .unwrap_or(0),
+    })
+}
+
+/// DB 정리 (VACUUM으로 빈 페이지 반환, ANALYZE로 쿼리 통계 갱신)
+///
+/// WAL 모드에서는 VACUUM 결과가 WAL에 쌓이므로 전후로 체크포인트 후 WAL을 비운다.
+pub fn compact(conn: &Connection) -> AppResult<()> {
+    checkpoint_truncate(conn)?;
+    conn.execute_batch("VACUUM; ANALYZE;")?;
+    checkpoint_truncate(conn)
+}
+
+fn checkpoint_truncate(conn: &Connection) -> AppResult<()> {
+    // (busy, log, checkpointed) 결과 행은 사용하지 않음
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+    Ok(())
+}
+
+/// DB를 다른 경로로 복사한 뒤 검증 (무결성 검사 + 테이블별 행 수 비교)
+///
+/// 체크포인트로 WAL 내용을 원본 파일에 반영한 뒤 online backup API로 복사한다.
+pub fn copy_verified(conn: &Connection, target: &Path) -> AppResult<()> {
+    checkpoint_truncate(conn)?;
+    conn.backup(DatabaseName::Main, target, None)?;
+
+    let copy = Connection::open(target)?;
+    let integrity: String = copy.query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+    if integrity != "ok" {
+        log::error!("[DB] Copy integrity check failed: {}", integrity);
+        return Err(AppError::Internal(
+            "DB 복사본 검증에 실패했습니다.".to_string(),
+        ));
+    }
+
+    let expected = table_stats(conn)?;
+    let actual = table_stats(&copy)?;
+    if expected != actual {
+        log::error!(
+            "[DB] Copy row counts differ: {:?} != {:?}",
+            expected,
+            actual
+        );
+        return Err(AppError::Internal(
+            "DB 복사본 검증에 실패했습니다.".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// DB 파일과 WAL/공유 메모리 파일 삭제 (없는 파일은 무시)
+pub fn remove_db_files(db_path: &Path) -> AppResult<()> {
+    remove_file_if_exists(db_path)?;
+    remove_sidecar_files(db_path)
+}
+
+/// WAL/공유 메모리 파일 삭제 (DB 파일을 교체할 때 이전 파일의 WAL이 남지 않도록)
+pub fn remove_sidecar_files(db_path: &Path) -> AppResult<()> {
+    let mut shm_path = OsString::from(db_path.as_os_str());
+    shm_path.push("-shm");
+
+    remove_file_if_exists(&wal_path(db_path))?;
+    remove_file_if_exists(Path::new(&shm_path))
+}
+
+fn remove_file_if_exists(path: &Path) -> AppResult<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -1,19 +1,116 @@
-use rusqlite::Connection;
+use rusqlite::{Connection, Transaction};
 
 use super::schema::SCHEMA_SQL;
+use crate::error::{AppError, AppResult};
+use crate::settings;
+
+/// 번호 순서대로 한 번씩 실행되는 마이그레이션
+///
+/// 실행한 마지막 번호는 `PRAGMA user_version`에 기록된다.
+/// 이미 배포된 마이그레이션은 수정하지 않고 새 번호를 추가한다.
+struct Migration {
+    version: u32,
+    description: &'static str,
+    up: fn(&Transaction) -> AppResult<()>,
+}
+
+/// 마이그레이션 목록 (version은 1부터 빈 번호 없이 증가)
+///
+/// user_version 도입 전에 만든 DB는 0에서 시작하므로
+/// 컬럼 추가는 이미 있는 컬럼을 건너뛰도록 작성한다.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "기본 스키마",
+        up: |tx| Ok(tx.execute_batch(SCHEMA_SQL)?),
+    },
+    Migration {
+        version: 2,
+        description: "일시정지 시 남은 시간",
+        up: |tx| add_column_if_missing(tx, "tbl_task", "remaining_time_seconds", "INTEGER"),
+    },
+    Migration {
+        version: 3,
+        description: "사이클 모드 (실행 단계, 체크포인트 진행 상태)",
+        up: |tx| {
+            add_column_if_missing(tx, "tbl_task_run_history", "phase", "TEXT NOT NULL DEFAULT 'focus'")?;
+            add_column_if_missing(tx, "tbl_timer_checkpoint", "cycle", "TEXT")
+        },
+    },
+    Migration {
+        version: 4,
+        description: "초과 시간 모드",
+        up: |tx| {
+            add_column_if_missing(tx, "tbl_task_run_history", "overtime_seconds", "INTEGER NOT NULL DEFAULT 0")?;
+            add_column_if_missing(tx, "tbl_timer_checkpoint", "mode", "TEXT NOT NULL DEFAULT 'countdown'")?;
+            add_column_if_missing(tx, "tbl_timer_checkpoint", "overtime_seconds", "INTEGER NOT NULL DEFAULT 0")
+        },
+    },
+    Migration {
+        version: 5,
+        description: "스톱워치 경과 시간",
+        up: |tx| add_column_if_missing(tx, "tbl_timer_checkpoint", "elapsed_seconds", "INTEGER NOT NULL DEFAULT 0"),
+    },
+];
+
+/// 마이그레이션 없이 읽을 때 필요한 컬럼 (user_version 도입 전 DB에도 있는 컬럼)
+///
+/// 이후 마이그레이션에서 추가된 컬럼은 읽는 쪽에서 `has_column`으로 확인하고 기본값을 사용한다.
+const READABLE_SCHEMA: &[(&str, &str)] = &[
+    ("tbl_setting", "key"),
+    ("tbl_task", "remaining_time_seconds"),
+    ("tbl_task_tag", "task_id"),
+    ("tbl_task_memo", "task_id"),
+    ("tbl_task_note", "task_id"),
+    ("tbl_task_run_history", "task_id"),
+    ("tbl_task_time_extension", "task_id"),
+    ("tbl_task_action_history", "task_id"),
+];
+
+/// 앱이 지원하는 스키마 버전 (마지막 마이그레이션 번호)
+pub fn latest_schema_version() -> u32 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+/// DB에 기록된 스키마 버전
+pub fn schema_version(conn: &Connection) -> AppResult<u32> {
+    Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+}
 
 /// 데이터베이스 마이그레이션 실행
-pub fn run_migrations(conn: &Connection) -> Result<(), String> {
-    // Foreign Key 활성화
+pub fn run_migrations(conn: &Connection) -> AppResult<()> {
+    // Foreign Key 활성화 (트랜잭션 안에서는 적용되지 않으므로 먼저 실행)
     conn.execute("PRAGMA foreign_keys = ON", [])
-        .map_err(|e| format!("Failed to enable foreign keys: {}", e))?;
+        .map_err(|e| AppError::Database(format!("Failed to enable foreign keys: {}", e)))?;
+
+    let current = schema_version(conn)?;
+    let latest = latest_schema_version();
+
+    // 더 최신 앱에서 만든 DB는 열지 않음 (모르는 컬럼/테이블을 손상시키지 않도록)
+    if current > latest {
+        log::error!("[Migration] DB schema v{} is newer than supported v{}", current, latest);
+        return Err(AppError::UnsupportedSchema {
+            db_version: current,
+            app_version: latest,
+        });
+    }
 
-    // 스키마 생성 (멱등성 보장 - IF NOT EXISTS)
-    conn.execute_batch(SCHEMA_SQL)
-        .map_err(|e| format!("Failed to create schema: {}", e))?;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        log::info!("[Migration] v{}: {}", migration.version, migration.description);
+
+        // 마이그레이션마다 트랜잭션으로 실행하여 실패 시 이전 버전 유지
+        let tx = conn.unchecked_transaction()?;
+        (migration.up)(&tx).map_err(|e| {
+            log::error!("[Migration] v{} failed: {}", migration.version, e);
+            e
+        })?;
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+    }
 
-    // 증분 마이그레이션 실행
-    run_incremental_migrations(conn)?;
+    if current < latest {
+        log::info!("[Migration] Schema upgraded v{} -> v{}", current, latest);
+    }
 
     // 기본 설정값 시드
     seed_default_settings(conn)?;
@@ -21,75 +118,122 @@ pub fn run_migrations(conn: &Connection) -> Result<(), String> {
     Ok(())
 }
 
-/// 증분 마이그레이션 (기존 테이블에 새 컬럼 추가)
-fn run_incremental_migrations(conn: &Connection) -> Result<(), String> {
-    println!("[Migration] Running incremental migrations...");
-    
-    // remaining_time_seconds 컬럼 추가 (일시정지 시 남은 시간 저장용)
-    let has_column = conn.query_row(
-        "SELECT COUNT(*) FROM pragma_table_info('tbl_task') WHERE name = 'remaining_time_seconds'",
-        [],
-        |row| row.get::<_, i64>(0),
-    ).map_err(|e| format!("Failed to check column existence: {}", e))?;
-
-    println!("[Migration] has_column remaining_time_seconds: {}", has_column);
-
-    if has_column == 0 {
-        println!("[Migration] Adding remaining_time_seconds column...");
+/// 마이그레이션하지 않고 읽을 수 있는 DB인지 확인 (읽기 전용으로 열 때)
+/// 기본 테이블/컬럼이 없으면 `UNREADABLE_SCHEMA`
+pub fn check_readable_schema(conn: &Connection) -> AppResult<()> {
+    let mut missing = Vec::new();
+    for (table, column) in READABLE_SCHEMA {
+        if !has_column(conn, table, column)? {
+            missing.push(format!("{}.{}", table, column));
+        }
+    }
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+    log::warn!("[Migration] Unreadable schema, missing: {}", missing.join(", "));
+    Err(AppError::UnreadableSchema(missing.join(", ")))
+}
+
+/// 컬럼 존재 여부 (테이블이 없어도 false)
+pub fn has_column(conn: &Connection, table: &str, column: &str) -> AppResult<bool> {
+    let count = conn
+        .prepare_cached("SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2")?
+        .query_row([table, column], |row| row.get::<_, i64>(0))?;
+    Ok(count > 0)
+}
+
+/// 컬럼이 없으면 추가
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> AppResult<()> {
+    let has_column = has_column(conn, table, column)
+        .map_err(|e| AppError::Database(format!("Failed to check column existence: {}", e)))?;
+
+    if !has_column {
+        log::info!("[Migration] Adding {}.{} column", table, column);
         conn.execute(
-            "ALTER TABLE tbl_task ADD COLUMN remaining_time_seconds INTEGER",
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
             [],
-        ).map_err(|e| format!("Failed to add remaining_time_seconds column: {}", e))?;
-        println!("[Migration] Column added successfully!");
+        ).map_err(|e| AppError::Database(format!("Failed to add {} column: {}", column, e)))?;
     } else {
-        println!("[Migration] Column already exists, skipping.");
+        log::debug!("[Migration] {}.{} already exists, skipping", table, column);
     }
 
     Ok(())
 }
 
-/// 기본 설정값 삽입
-fn seed_default_settings(conn: &Connection) -> Result<(), String> {
-    let defaults = vec![
-        ("schema_version", "1"),
-        ("theme", "\"system\""),
-        ("language", "\"ko\""),
-        ("timer_default_minutes", "5"),
-        ("notification_sound", "true"),
-        ("notification_vibration", "true"),
-    ];
-
-    for (key, value) in defaults {
-        conn.execute(
-            r#"
-            INSERT OR IGNORE INTO tbl_setting (id, key, value, updated_at)
-            VALUES (
-                'setting_' || ?1,
-                ?1,
-                ?2,
-                datetime('now')
-            )
-            "#,
-            rusqlite::params![key, value],
-        )
-        .map_err(|e| format!("Failed to seed setting '{}': {}", key, e))?;
-    }
+/// 기본 설정값 삽입 (설정 목록은 settings::REGISTRY)
+fn seed_default_settings(conn: &Connection) -> AppResult<()> {
+    settings::seed_defaults(conn)?;
+
+    // schema_version 설정은 조회용으로 user_version과 맞춰 둠
+    conn.execute(
+        r#"
+        INSERT INTO tbl_setting (id, key, value, updated_at)
+        VALUES ('setting_schema_version', 'schema_version', ?1, datetime('now'))
+        ON CONFLICT(key) DO UPDATE SET value = ?1, updated_at = datetime('now')
+        WHERE value IS NOT ?1
+        "#,
+        [latest_schema_version().to_string()],
+    )
+    .map_err(|e| AppError::Database(format!("Failed to update schema_version: {}", e)))?;
 
     Ok(())
 }
 
 /// 테이블 목록 조회
-pub fn get_table_list(conn: &Connection) -> Result<Vec<String>, String> {
+pub fn get_table_list(conn: &Connection) -> AppResult<Vec<String>> {
     let mut stmt = conn
-        .prepare("SELECT name FROM sqlite_master WHERE type='table' AND name LIKE 'tbl_%' ORDER BY name")
-        .map_err(|e| e.to_string())?;
+        .prepare("SELECT name FROM sqlite_master WHERE type='table' AND name LIKE 'tbl_%' ORDER BY name")?;
 
     let tables = stmt
-        .query_map([], |row| row.get(0))
-        .map_err(|e| e.to_string())?
+        .query_map([], |row| row.get(0))?
         .filter_map(|r| r.ok())
         .collect();
 
     Ok(tables)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 사이클/초과 시간 마이그레이션 이전 스키마
+    fn pre_series_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA_SQL).unwrap();
+        conn.execute_batch(
+            "ALTER TABLE tbl_task_run_history DROP COLUMN phase;
+             ALTER TABLE tbl_task_run_history DROP COLUMN overtime_seconds;
+             DROP TABLE tbl_timer_checkpoint;",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn pre_series_schema_is_readable() {
+        let conn = pre_series_db();
+
+        assert!(check_readable_schema(&conn).is_ok());
+        assert!(!has_column(&conn, "tbl_task_run_history", "phase").unwrap());
+        assert!(!has_column(&conn, "tbl_timer_checkpoint", "task_id").unwrap());
+    }
+
+    #[test]
+    fn missing_base_table_is_unreadable() {
+        let conn = pre_series_db();
+        conn.execute_batch("DROP TABLE tbl_task_action_history;").unwrap();
+
+        match check_readable_schema(&conn) {
+            Err(AppError::UnreadableSchema(details)) => {
+                assert_eq!(details, "tbl_task_action_history.task_id")
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
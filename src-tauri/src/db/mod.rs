@@ -1,7 +1,16 @@
+pub mod backup;
 pub mod commands;
+pub mod crypto;
+pub mod integrity;
+pub mod lock;
+pub mod maintenance;
 pub mod migration;
 pub mod models;
 pub mod schema;
+pub mod timer_store;
+pub mod watcher;
+pub mod worker;
+pub mod workspace;
 
 pub use commands::*;
 
@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// DB 상태 정보
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DbStatus {
     pub configured: bool,
@@ -9,6 +11,62 @@ pub struct DbStatus {
     pub exists: bool,
     pub size_bytes: Option<u64>,
     pub tables: Vec<String>,
+    /// 파일이 있을 때만 조회
+    pub stats: Option<DbStats>,
+    /// 마지막 백업 시각 (UTC, "%Y-%m-%d %H:%M:%S")
+    pub last_backup_at: Option<String>,
+    /// 읽기 전용으로 열림 (다른 인스턴스가 사용 중인 경우 등)
+    pub read_only: bool,
+    /// 다른 인스턴스가 보유한 잠금
+    pub lock_holder: Option<DbLockInfo>,
+    /// 잠금의 하트비트가 끊김 (확인 후 가져올 수 있음)
+    pub lock_stale: bool,
+    /// 암호화된 DB (SQLCipher)
+    pub encrypted: bool,
+    /// 암호화된 DB인데 암호가 입력되지 않음 (load_existing_db에 암호를 넘겨 잠금 해제)
+    pub needs_passphrase: bool,
+}
+
+/// DB 잠금 파일 ({db}.lock) 내용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbLockInfo {
+    pub host: String,
+    pub pid: u32,
+    pub instance_id: String,
+    /// 잠금 획득 시각 (UTC, "%Y-%m-%d %H:%M:%S")
+    pub acquired_at: String,
+    /// 마지막 하트비트 시각 (UTC)
+    pub heartbeat_at: String,
+}
+
+/// DB 통계
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbStats {
+    pub table_stats: Vec<TableStat>,
+    pub page_size: u64,
+    pub page_count: u64,
+    /// 사용하지 않는 페이지 수 (VACUUM으로 반환 가능)
+    pub freelist_count: u64,
+    pub wal_size_bytes: u64,
+}
+
+/// 테이블별 행 수
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStat {
+    pub name: String,
+    pub row_count: u64,
+}
+
+/// DB 정리 결과 (크기는 DB + WAL 파일 합계)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactReport {
+    pub size_before: u64,
+    pub size_after: u64,
+    pub reclaimed_bytes: u64,
 }
 
 /// Task 우선순위
@@ -119,6 +177,10 @@ pub struct TaskRunHistory {
     pub ended_at: Option<String>,
     pub duration: i64,
     pub end_type: String,
+    /// 실행 단계 (focus, short_break, long_break)
+    pub phase: String,
+    /// 카운트다운 종료 후 초과 시간 (초)
+    pub overtime_seconds: i64,
 }
 
 /// Task 시간 추가 히스토리
@@ -223,14 +285,27 @@ pub struct ExtendTimeInput {
     pub reason: Option<String>,
 }
 
-/// 앱 설정
+/// 실행 중 타이머 체크포인트 (앱 재시작 시 복구용)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Setting {
-    pub id: String,
-    pub key: String,
-    pub value: Option<String>,
-    pub updated_at: String,
+pub struct TimerCheckpoint {
+    pub task_id: String,
+    pub run_id: Option<String>,
+    pub task_title: String,
+    /// 벽시계 기준 종료 시각 (UTC)
+    pub deadline_at: String,
+    pub remaining_seconds: i64,
+    /// 사이클 모드 진행 상태 (JSON, 일반 타이머는 None)
+    pub cycle: Option<String>,
+    /// 타이머 모드 (countdown, overtime, stopwatch)
+    pub mode: String,
+    /// 체크포인트 시점까지의 초과 시간 (초)
+    pub overtime_seconds: i64,
+    /// 체크포인트 시점까지의 스톱워치 경과 시간 (초)
+    pub elapsed_seconds: i64,
+    /// 실행 기록 시작 시각 (UTC)
+    pub run_started_at: Option<String>,
+    pub checkpointed_at: String,
 }
 
 /// 테이블 행 (제네릭 조회용)
@@ -240,12 +315,100 @@ pub struct TableRow {
     pub values: Vec<serde_json::Value>,
 }
 
+/// DB 점검 문제 종류
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DbIssueKind {
+    /// task 하위 테이블 외의 외래 키 위반
+    ForeignKey,
+    /// 참조하는 task가 없는 행
+    OrphanRow,
+    /// 음수 시간 값
+    NegativeValue,
+    /// 알 수 없는 상태 문자열 (앱에서는 INBOX로 표시됨)
+    InvalidStatus,
+    /// 알 수 없는 우선순위 문자열 (앱에서는 MEDIUM으로 표시됨)
+    InvalidPriority,
+}
+
+/// DB 점검에서 발견한 문제
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbIssue {
+    pub kind: DbIssueKind,
+    pub table: String,
+    /// 행 식별 값 (id, 체크포인트는 task_id, 외래 키 위반은 rowid)
+    pub row_id: String,
+    pub column: Option<String>,
+    /// 문제가 된 값 (외래 키 위반은 참조 테이블)
+    pub value: Option<String>,
+    /// 복구 모드에서 수정 가능 여부
+    pub repairable: bool,
+}
+
+/// DB 점검 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbCheckReport {
+    /// 문제가 없으면 true (복구 모드에서는 복구 후 다시 점검한 결과)
+    pub ok: bool,
+    /// PRAGMA integrity_check 결과 ("ok" 제외, 있으면 백업 복원 필요)
+    pub integrity_errors: Vec<String>,
+    pub issues: Vec<DbIssue>,
+    /// 복구 모드에서 수정/삭제한 행 수
+    pub repaired_rows: usize,
+    /// 복구 직전 백업 파일명
+    pub backup_file: Option<String>,
+}
+
+/// DB 백업 파일 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub file_name: String,
+    pub path: String,
+    /// 백업 종류 (pre-migration, scheduled, pre-restore, pre-repair)
+    pub reason: String,
+    /// 생성 시각 (UTC, "%Y-%m-%d %H:%M:%S")
+    pub created_at: String,
+    pub size_bytes: u64,
+}
+
 /// 앱 설정 파일 (config.json)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AppConfig {
+    /// 활성 워크스페이스의 DB 경로
     pub db_path: Option<String>,
+    /// 기기별 설정 (키 → 값, settings::REGISTRY의 Machine 설정)
+    #[serde(default)]
+    pub settings: BTreeMap<String, serde_json::Value>,
+    /// 이전 버전의 theme/language 필드 (읽을 때 settings로 옮김)
+    #[serde(default, skip_serializing)]
     pub theme: Option<String>,
+    #[serde(default, skip_serializing)]
     pub language: Option<String>,
+    #[serde(default)]
+    pub workspaces: Vec<Workspace>,
+    pub active_workspace_id: Option<String>,
+}
+
+/// 이름 붙인 DB (config.json에 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    /// 마지막으로 연 시각 (UTC, "%Y-%m-%d %H:%M:%S")
+    pub last_opened_at: Option<String>,
+}
+
+/// 워크스페이스 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceList {
+    pub active_id: Option<String>,
+    pub workspaces: Vec<Workspace>,
 }
 
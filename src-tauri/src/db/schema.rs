@@ -80,6 +80,8 @@ CREATE TABLE IF NOT EXISTS tbl_task_run_history (
     ended_at TEXT,
     duration INTEGER NOT NULL DEFAULT 0,
     end_type TEXT NOT NULL,
+    phase TEXT NOT NULL DEFAULT 'focus',
+    overtime_seconds INTEGER NOT NULL DEFAULT 0,
     FOREIGN KEY(task_id) REFERENCES tbl_task(id) ON DELETE CASCADE
 );
 
@@ -114,5 +116,20 @@ CREATE TABLE IF NOT EXISTS tbl_task_action_history (
 CREATE INDEX IF NOT EXISTS idx_task_action_history_task_id ON tbl_task_action_history(task_id);
 CREATE INDEX IF NOT EXISTS idx_task_action_history_action_type ON tbl_task_action_history(action_type);
 CREATE INDEX IF NOT EXISTS idx_task_action_history_created_at ON tbl_task_action_history(created_at);
+
+-- 실행 중 타이머 체크포인트 테이블 (앱 재시작 시 복구용)
+CREATE TABLE IF NOT EXISTS tbl_timer_checkpoint (
+    task_id TEXT PRIMARY KEY,
+    run_id TEXT,
+    task_title TEXT NOT NULL,
+    deadline_at TEXT NOT NULL,
+    remaining_seconds INTEGER NOT NULL,
+    cycle TEXT,
+    mode TEXT NOT NULL DEFAULT 'countdown',
+    overtime_seconds INTEGER NOT NULL DEFAULT 0,
+    elapsed_seconds INTEGER NOT NULL DEFAULT 0,
+    checkpointed_at TEXT NOT NULL,
+    FOREIGN KEY(task_id) REFERENCES tbl_task(id) ON DELETE CASCADE
+);
 "#;
 
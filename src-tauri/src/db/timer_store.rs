@@ -0,0 +1,518 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde_json::json;
+
+use super::commands::{add_action_history_internal, status_action_type};
+use super::models::TimerCheckpoint;
+use crate::error::{AppError, AppResult};
+
+/// DB 타임스탬프 포맷 (SQLite `datetime('now')`와 동일한 UTC 문자열)
+pub const DB_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 타이머 실행 기록 시작 (phase: focus, short_break, long_break)
+pub fn begin_run(
+    conn: &Connection,
+    task_id: &str,
+    phase: &str,
+    started_at: &str,
+) -> AppResult<String> {
+    let id = uuid::Uuid::new_v4().to_string();
+    let tx = conn.unchecked_transaction()?;
+
+    tx.execute(
+        r#"
+        INSERT INTO tbl_task_run_history (id, task_id, started_at, duration, end_type, phase)
+        VALUES (?1, ?2, ?3, 0, 'running', ?4)
+        "#,
+        rusqlite::params![id, task_id, started_at, phase],
+    )?;
+
+    tx.execute(
+        "UPDATE tbl_task SET last_run_at = ?2, updated_at = datetime('now') WHERE id = ?1",
+        rusqlite::params![task_id, started_at],
+    )?;
+
+    tx.commit()?;
+    Ok(id)
+}
+
+/// 실행 중 타이머 상태 저장 (체크포인트, 실행 기록 duration, 남은 시간)
+pub fn save_checkpoints(
+    conn: &Connection,
+    checkpoints: &[(TimerCheckpoint, i64)],
+) -> AppResult<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    for (checkpoint, run_duration) in checkpoints {
+        tx.execute(
+            r#"
+            INSERT INTO tbl_timer_checkpoint (task_id, run_id, task_title, deadline_at, remaining_seconds, cycle, mode, overtime_seconds, elapsed_seconds, checkpointed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            ON CONFLICT(task_id) DO UPDATE SET
+                run_id = ?2, task_title = ?3, deadline_at = ?4, remaining_seconds = ?5, cycle = ?6,
+                mode = ?7, overtime_seconds = ?8, elapsed_seconds = ?9, checkpointed_at = ?10
+            "#,
+            rusqlite::params![
+                checkpoint.task_id,
+                checkpoint.run_id,
+                checkpoint.task_title,
+                checkpoint.deadline_at,
+                checkpoint.remaining_seconds,
+                checkpoint.cycle,
+                checkpoint.mode,
+                checkpoint.overtime_seconds,
+                checkpoint.elapsed_seconds,
+                checkpoint.checkpointed_at,
+            ],
+        )?;
+
+        if let Some(run_id) = &checkpoint.run_id {
+            tx.execute(
+                r#"
+                UPDATE tbl_task_run_history SET duration = ?2, overtime_seconds = ?3
+                WHERE id = ?1 AND end_type = 'running'
+                "#,
+                rusqlite::params![run_id, run_duration, checkpoint.overtime_seconds],
+            )?;
+        }
+
+        tx.execute(
+            "UPDATE tbl_task SET remaining_time_seconds = ?2 WHERE id = ?1",
+            rusqlite::params![checkpoint.task_id, checkpoint.remaining_seconds],
+        )?;
+    }
+
+    tx.commit().map_err(AppError::from)
+}
+
+/// 타이머 실행 기록 종료 및 체크포인트 제거 (overtime: 초과 시간, 초)
+pub fn finish_run(
+    conn: &Connection,
+    task_id: &str,
+    run_id: Option<&str>,
+    end_type: &str,
+    ended_at: &str,
+    duration: i64,
+    overtime: i64,
+) -> AppResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    close_run(&tx, task_id, run_id, end_type, ended_at, duration, overtime)?;
+    tx.commit().map_err(AppError::from)
+}
+
+/// 카운트다운 종료 처리 (웹뷰가 없어도 시간 기록이 정확하도록 한 트랜잭션에서 처리)
+/// - 실행 기록을 `end_type`(`timeout`, 사이클 완료 시 `completed`)으로 종료하고 total_time_spent 재계산
+/// - 남은 시간 0으로 저장, 실행 중이던 task는 PAUSED로 전환
+/// - TIMER_ENDED 액션 히스토리 기록
+pub fn expire_timer(
+    conn: &Connection,
+    task_id: &str,
+    run_id: Option<&str>,
+    end_type: &str,
+    ended_at: &str,
+    duration: i64,
+) -> AppResult<()> {
+    let tx = conn.unchecked_transaction()?;
+    close_run(&tx, task_id, run_id, end_type, ended_at, duration, 0)?;
+
+    let previous_status: Option<String> = tx
+        .query_row(
+            "SELECT status FROM tbl_task WHERE id = ?1",
+            [task_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    // task가 삭제된 경우 실행 기록만 정리
+    let Some(previous_status) = previous_status else {
+        return tx.commit().map_err(AppError::from);
+    };
+
+    let new_status = if previous_status == "IN_PROGRESS" {
+        "PAUSED"
+    } else {
+        previous_status.as_str()
+    };
+
+    tx.execute(
+        r#"
+        UPDATE tbl_task
+        SET remaining_time_seconds = 0, status = ?2, last_paused_at = ?3, updated_at = datetime('now')
+        WHERE id = ?1
+        "#,
+        rusqlite::params![task_id, new_status, ended_at],
+    )?;
+
+    let metadata = json!({ "runId": run_id, "duration": duration }).to_string();
+    add_action_history_internal(
+        &tx,
+        task_id,
+        "TIMER_ENDED",
+        Some(&previous_status),
+        Some(new_status),
+        Some(&metadata),
+    )?;
+
+    tx.commit().map_err(AppError::from)
+}
+
+/// 실행 기록 종료, total_time_spent에 이번 실행 시간 합산(분, 휴식 단계 제외), 체크포인트 제거
+fn close_run(
+    conn: &Connection,
+    task_id: &str,
+    run_id: Option<&str>,
+    end_type: &str,
+    ended_at: &str,
+    duration: i64,
+    overtime: i64,
+) -> AppResult<()> {
+    if let Some(run_id) = run_id {
+        // 기록을 종료하기 전에 합산해야 이미 종료된 기록을 다시 더하지 않음
+        add_run_to_total(conn, task_id, run_id, duration)?;
+        conn.execute(
+            r#"
+            UPDATE tbl_task_run_history
+            SET ended_at = ?2, duration = ?3, end_type = ?4, overtime_seconds = ?5
+            WHERE id = ?1
+            "#,
+            rusqlite::params![run_id, ended_at, duration, end_type, overtime],
+        )?;
+    }
+
+    conn.execute(
+        "DELETE FROM tbl_timer_checkpoint WHERE task_id = ?1",
+        [task_id],
+    )?;
+
+    Ok(())
+}
+
+/// 진행 중인 집중 실행 기록의 시간을 total_time_spent(분)에 더함
+///
+/// 실행 기록이 생기기 전의 누적 시간을 유지하도록 다시 계산하지 않고 더한다.
+/// 분 단위로 바로 나누면 실행마다 1분 미만이 버려지므로, 종료된 집중 기록의 누적 초가
+/// 이번 실행으로 넘어선 분만큼 더한다.
+fn add_run_to_total(
+    conn: &Connection,
+    task_id: &str,
+    run_id: &str,
+    duration: i64,
+) -> AppResult<()> {
+    let counted = conn
+        .query_row(
+            "SELECT phase = 'focus' AND end_type = 'running' FROM tbl_task_run_history WHERE id = ?1",
+            [run_id],
+            |row| row.get::<_, bool>(0),
+        )
+        .optional()?
+        .unwrap_or(false);
+    if !counted {
+        return Ok(());
+    }
+
+    let previous_secs: i64 = conn.query_row(
+        r#"
+        SELECT COALESCE(SUM(duration), 0) FROM tbl_task_run_history
+        WHERE task_id = ?1 AND end_type != 'running' AND phase = 'focus'
+        "#,
+        [task_id],
+        |row| row.get(0),
+    )?;
+    let added = (previous_secs + duration.max(0)) / 60 - previous_secs / 60;
+    if added > 0 {
+        conn.execute(
+            "UPDATE tbl_task SET total_time_spent = total_time_spent + ?2 WHERE id = ?1",
+            rusqlite::params![task_id, added],
+        )?;
+    }
+    Ok(())
+}
+
+/// 앱 시작 시 실행 중이던 타이머 복구
+///
+/// `resume`이 true면 여전히 IN_PROGRESS인 task의 체크포인트를 반환하여 타이머 엔진이 이어서 실행한다.
+/// 그 외에는 마지막 체크포인트 시점 기준으로 실행 기록을 `interrupted`로 닫고 PAUSED로 전환한다.
+/// 체크포인트가 없는 `running` 실행 기록도 기록된 duration 기준으로 닫는다.
+pub fn recover_running_tasks(conn: &Connection, resume: bool) -> AppResult<Vec<TimerCheckpoint>> {
+    let checkpoints = load_checkpoints(conn)?;
+    let tx = conn.unchecked_transaction()?;
+    let mut resumed = Vec::new();
+
+    for (checkpoint, status) in checkpoints {
+        if resume && status == "IN_PROGRESS" {
+            resumed.push(checkpoint);
+            continue;
+        }
+
+        if let Some(run_id) = &checkpoint.run_id {
+            let duration: Option<i64> = tx
+                .query_row(
+                    r#"
+                    SELECT MAX(0, CAST(ROUND((julianday(?2) - julianday(started_at)) * 86400) AS INTEGER))
+                    FROM tbl_task_run_history WHERE id = ?1
+                    "#,
+                    rusqlite::params![run_id, checkpoint.checkpointed_at],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if let Some(duration) = duration {
+                add_run_to_total(&tx, &checkpoint.task_id, run_id, duration)?;
+            }
+            tx.execute(
+                r#"
+                UPDATE tbl_task_run_history
+                SET ended_at = ?2,
+                    duration = MAX(0, CAST(ROUND((julianday(?2) - julianday(started_at)) * 86400) AS INTEGER)),
+                    overtime_seconds = ?3,
+                    end_type = 'interrupted'
+                WHERE id = ?1 AND end_type = 'running'
+                "#,
+                rusqlite::params![run_id, checkpoint.checkpointed_at, checkpoint.overtime_seconds],
+            )?;
+        }
+
+        if status == "IN_PROGRESS" {
+            tx.execute(
+                r#"
+                UPDATE tbl_task
+                SET status = 'PAUSED', remaining_time_seconds = ?2, last_paused_at = ?3, updated_at = datetime('now')
+                WHERE id = ?1
+                "#,
+                rusqlite::params![
+                    checkpoint.task_id,
+                    checkpoint.remaining_seconds,
+                    checkpoint.checkpointed_at,
+                ],
+            )?;
+
+            add_action_history_internal(
+                &tx,
+                &checkpoint.task_id,
+                "PAUSED",
+                Some("IN_PROGRESS"),
+                Some("PAUSED"),
+                Some(r#"{"reason":"interrupted"}"#),
+            )?;
+        }
+
+        tx.execute(
+            "DELETE FROM tbl_timer_checkpoint WHERE task_id = ?1",
+            [&checkpoint.task_id],
+        )?;
+    }
+
+    // 체크포인트 없이 IN_PROGRESS로 남은 task는 일시정지
+    tx.execute(
+        r#"
+        UPDATE tbl_task SET status = 'PAUSED'
+        WHERE status = 'IN_PROGRESS'
+          AND id NOT IN (SELECT task_id FROM tbl_timer_checkpoint)
+        "#,
+        [],
+    )?;
+
+    // 이어서 실행하지 않는 실행 기록은 마지막으로 기록된 duration 기준으로 종료 (누적 시간에 먼저 반영)
+    let interrupted: Vec<(String, String, i64)> = tx
+        .prepare(
+            r#"
+            SELECT id, task_id, duration FROM tbl_task_run_history
+            WHERE end_type = 'running'
+              AND id NOT IN (SELECT run_id FROM tbl_timer_checkpoint WHERE run_id IS NOT NULL)
+            "#,
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<_, _>>()?;
+    for (run_id, task_id, duration) in &interrupted {
+        add_run_to_total(&tx, task_id, run_id, *duration)?;
+    }
+    tx.execute(
+        r#"
+        UPDATE tbl_task_run_history
+        SET ended_at = datetime(started_at, '+' || duration || ' seconds'),
+            end_type = 'interrupted'
+        WHERE end_type = 'running'
+          AND id NOT IN (SELECT run_id FROM tbl_timer_checkpoint WHERE run_id IS NOT NULL)
+        "#,
+        [],
+    )?;
+
+    tx.commit()?;
+    Ok(resumed)
+}
+
+/// 체크포인트 목록 조회 (task 상태 포함)
+fn load_checkpoints(conn: &Connection) -> AppResult<Vec<(TimerCheckpoint, String)>> {
+    let mut stmt = conn.prepare(
+        r#"
+            SELECT c.task_id, c.run_id, c.task_title, c.deadline_at, c.remaining_seconds,
+                   c.cycle, c.mode, c.overtime_seconds, c.elapsed_seconds, r.started_at,
+                   c.checkpointed_at, t.status
+            FROM tbl_timer_checkpoint c
+            JOIN tbl_task t ON t.id = c.task_id
+            LEFT JOIN tbl_task_run_history r ON r.id = c.run_id
+            "#,
+    )?;
+
+    let checkpoints = stmt
+        .query_map([], |row| {
+            Ok((
+                TimerCheckpoint {
+                    task_id: row.get(0)?,
+                    run_id: row.get(1)?,
+                    task_title: row.get(2)?,
+                    deadline_at: row.get(3)?,
+                    remaining_seconds: row.get(4)?,
+                    cycle: row.get(5)?,
+                    mode: row.get(6)?,
+                    overtime_seconds: row.get(7)?,
+                    elapsed_seconds: row.get(8)?,
+                    run_started_at: row.get(9)?,
+                    checkpointed_at: row.get(10)?,
+                },
+                row.get::<_, String>(11)?,
+            ))
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(checkpoints)
+}
+
+/// 진행 중/일시정지 task (트레이 메뉴 등 웹뷰 밖에서 사용)
+#[derive(Debug, Clone)]
+pub struct ActiveTask {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub expected_duration: Option<i64>,
+    pub remaining_time_seconds: Option<i64>,
+}
+
+/// 진행 중/일시정지 task 목록 (진행 중 우선, 최근 실행 순)
+pub fn list_active_tasks(conn: &Connection) -> AppResult<Vec<ActiveTask>> {
+    let mut stmt = conn.prepare_cached(
+        r#"
+            SELECT id, title, status, expected_duration, remaining_time_seconds
+            FROM tbl_task
+            WHERE status IN ('IN_PROGRESS', 'PAUSED')
+            ORDER BY status = 'IN_PROGRESS' DESC, COALESCE(last_run_at, updated_at) DESC
+            "#,
+    )?;
+
+    let tasks = stmt
+        .query_map([], |row| {
+            Ok(ActiveTask {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                status: row.get(2)?,
+                expected_duration: row.get(3)?,
+                remaining_time_seconds: row.get(4)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(tasks)
+}
+
+/// task 상태 변경 (update_task와 같은 필드 갱신 및 액션 히스토리 기록)
+/// remaining_seconds가 None이면 저장된 남은 시간 유지
+pub fn change_task_status(
+    conn: &Connection,
+    task_id: &str,
+    new_status: &str,
+    remaining_seconds: Option<i64>,
+) -> AppResult<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    let previous_status: Option<String> = tx
+        .query_row(
+            "SELECT status FROM tbl_task WHERE id = ?1",
+            [task_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(previous_status) = previous_status else {
+        return Err(AppError::NotFound("Task를 찾을 수 없습니다.".to_string()));
+    };
+    if previous_status == new_status {
+        return Ok(());
+    }
+
+    tx.execute(
+        r#"
+        UPDATE tbl_task
+        SET status = ?2,
+            remaining_time_seconds = COALESCE(?3, remaining_time_seconds),
+            last_paused_at = CASE WHEN ?2 = 'PAUSED' THEN datetime('now') ELSE last_paused_at END,
+            completed_at = CASE WHEN ?2 = 'COMPLETED' THEN datetime('now') ELSE completed_at END,
+            last_run_at = CASE WHEN ?2 = 'IN_PROGRESS' THEN datetime('now') ELSE last_run_at END,
+            updated_at = datetime('now')
+        WHERE id = ?1
+        "#,
+        rusqlite::params![task_id, new_status, remaining_seconds],
+    )?;
+
+    add_action_history_internal(
+        &tx,
+        task_id,
+        status_action_type(new_status),
+        Some(&previous_status),
+        Some(new_status),
+        None,
+    )?;
+
+    tx.commit().map_err(AppError::from)
+}
+
+/// 예상 시간 연장 (시간 추가 히스토리 기록, 일시정지 중 저장된 남은 시간도 연장)
+pub fn extend_task(
+    conn: &Connection,
+    task_id: &str,
+    added_minutes: i64,
+    reason: Option<&str>,
+) -> AppResult<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    let previous_duration: Option<i64> = tx
+        .query_row(
+            "SELECT expected_duration FROM tbl_task WHERE id = ?1",
+            [task_id],
+            |row| row.get(0),
+        )
+        .optional()?
+        .ok_or_else(|| AppError::NotFound("Task를 찾을 수 없습니다.".to_string()))?;
+    let Some(previous_duration) = previous_duration else {
+        return Err(AppError::InvalidInput(
+            "예상 시간이 없는 task입니다.".to_string(),
+        ));
+    };
+    let new_duration = previous_duration + added_minutes;
+
+    tx.execute(
+        r#"
+        INSERT INTO tbl_task_time_extension (id, task_id, added_minutes, previous_duration, new_duration, reason)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+        rusqlite::params![
+            uuid::Uuid::new_v4().to_string(),
+            task_id,
+            added_minutes,
+            previous_duration,
+            new_duration,
+            reason,
+        ],
+    )?;
+
+    tx.execute(
+        r#"
+        UPDATE tbl_task
+        SET expected_duration = ?2,
+            remaining_time_seconds = remaining_time_seconds + ?3,
+            updated_at = datetime('now')
+        WHERE id = ?1
+        "#,
+        rusqlite::params![task_id, new_duration, added_minutes * 60],
+    )?;
+
+    tx.commit().map_err(AppError::from)
+}
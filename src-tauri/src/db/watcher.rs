@@ -0,0 +1,48 @@
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use super::commands::{migrate, DbState};
+use crate::error::AppResult;
+
+/// DB 파일 외부 변경 확인 주기
+const WATCH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// DB 파일 감시 루프
+///
+/// Dropbox/iCloud 동기화 등으로 파일이 바뀌면 연결을 다시 열고 마이그레이션한 뒤
+/// `db-changed`를 보내 모든 창이 다시 불러오도록 한다.
+/// 앱 자체의 쓰기는 작업 스레드가 작업마다 파일 상태를 기록하므로 변경으로 보지 않는다.
+pub async fn run_db_watcher(app_handle: AppHandle) {
+    let mut ticker = interval(WATCH_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = check_db_file(&app_handle).await {
+            log::warn!("[DB] Failed to check external changes: {}", e);
+        }
+    }
+}
+
+async fn check_db_file(app_handle: &AppHandle) -> AppResult<()> {
+    let state = app_handle.state::<DbState>();
+    let Some(db_path) = state.db_path.lock()?.clone() else {
+        return Ok(());
+    };
+    // 동기화 중 잠시 사라진 파일을 빈 DB로 다시 만들지 않도록 건너뜀
+    if !db_path.exists() {
+        return Ok(());
+    }
+    if !state.worker.detect_external_change(db_path.clone()).await? {
+        return Ok(());
+    }
+
+    log::info!("[DB] External change detected: {}", db_path.display());
+    // 읽기 전용이면 파일을 바꾸지 않도록, 암호 입력 전이면 열 수 없으므로 마이그레이션하지 않음
+    if !state.worker.is_read_only(&db_path) && !state.worker.needs_passphrase(&db_path) {
+        migrate(app_handle, &state, &db_path).await?;
+    }
+    crate::tray::refresh_menu(app_handle);
+    let _ = app_handle.emit("db-changed", db_path.to_string_lossy().to_string());
+    Ok(())
+}
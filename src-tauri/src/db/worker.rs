@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use super::crypto;
+use super::maintenance::{remove_sidecar_files, wal_path};
+use crate::error::{AppError, AppResult};
+use rusqlite::{Connection, OpenFlags};
+use tokio::sync::oneshot;
+
+/// 준비된 statement 캐시 크기
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+/// 다른 연결이 쓰기 중일 때 대기 시간
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 작업 스레드에서 실행할 DB 작업
+type Job = Box<dyn FnOnce(&mut WorkerConnection) + Send>;
+
+/// 외부 변경 감지용 파일 상태 (DB, WAL 파일의 크기와 수정 시각)
+#[derive(Debug, Clone, PartialEq)]
+struct FileFingerprint {
+    db: (u64, Option<SystemTime>),
+    wal: Option<(u64, Option<SystemTime>)>,
+}
+
+impl FileFingerprint {
+    fn of(path: &Path) -> Option<Self> {
+        let stat = |p: &Path| fs::metadata(p).ok().map(|m| (m.len(), m.modified().ok()));
+        Some(Self {
+            db: stat(path)?,
+            wal: stat(&wal_path(path)),
+        })
+    }
+}
+
+/// 읽기 전용으로 열 DB 경로 (DbWorker와 작업 스레드가 공유)
+type ReadOnlyPath = Arc<Mutex<Option<PathBuf>>>;
+
+/// 암호화된 DB의 암호 (메모리에만 보관, 로그아웃 시 삭제)
+type Passphrases = Arc<Mutex<HashMap<PathBuf, String>>>;
+
+/// 작업 스레드가 보관하는 연결 (DB 경로나 읽기 전용 여부가 바뀌면 다시 연결)
+struct WorkerConnection {
+    current: Option<(PathBuf, Connection)>,
+    current_read_only: bool,
+    current_passphrase: Option<String>,
+    read_only_path: ReadOnlyPath,
+    passphrases: Passphrases,
+    /// 마지막 작업 직후의 파일 상태 (앱 자체의 쓰기는 외부 변경으로 보지 않도록 작업마다 갱신)
+    last_seen: Option<(PathBuf, Option<FileFingerprint>)>,
+    /// 작업 전 확인에서 외부 변경을 발견한 DB (감시 작업이 확인할 때까지 유지)
+    pending_change: Option<PathBuf>,
+    /// 이번 작업 전 파일 상태가 마지막 기록과 같았는지 (같을 때만 작업 후 상태를 기록)
+    fingerprint_matched: bool,
+}
+
+impl WorkerConnection {
+    fn new(read_only_path: ReadOnlyPath, passphrases: Passphrases) -> Self {
+        Self {
+            current: None,
+            current_read_only: false,
+            current_passphrase: None,
+            read_only_path,
+            passphrases,
+            last_seen: None,
+            pending_change: None,
+            fingerprint_matched: false,
+        }
+    }
+
+    fn get(&mut self, path: &Path) -> AppResult<&mut Connection> {
+        // 작업 전에 파일이 바뀌었으면 교체된 파일을 다시 열고 감시 작업이 알리도록 표시
+        // (이미 표시했으면 감시 작업이 확인할 때까지 작업 후 상태를 기록하지 않음)
+        if self.pending_change.as_deref() != Some(path) {
+            if self.changed_since_last_seen(path) {
+                log::warn!("[DB] File changed before job: {}", path.display());
+                if self.current.as_ref().is_some_and(|(p, _)| p == path) {
+                    self.current = None;
+                }
+                self.pending_change = Some(path.to_path_buf());
+            } else {
+                self.fingerprint_matched = true;
+            }
+        }
+
+        let read_only = self
+            .read_only_path
+            .lock()
+            .map(|p| p.as_deref() == Some(path))
+            .unwrap_or(false);
+        let passphrase = self
+            .passphrases
+            .lock()
+            .ok()
+            .and_then(|p| p.get(path).cloned());
+        if self.current.as_ref().map(|(p, _)| p.as_path()) != Some(path)
+            || self.current_read_only != read_only
+            || self.current_passphrase != passphrase
+        {
+            // 이전 연결을 먼저 닫아 WAL 체크포인트가 반영되도록 함
+            self.current = None;
+            let conn = open(path, read_only, passphrase.as_deref())?;
+            log::info!(
+                "[DB] Connection opened: {}{}",
+                path.display(),
+                if read_only { " (read-only)" } else { "" }
+            );
+            self.current = Some((path.to_path_buf(), conn));
+            self.current_read_only = read_only;
+            self.current_passphrase = passphrase;
+        }
+        Ok(&mut self.current.as_mut().unwrap().1)
+    }
+
+    /// 작업 후 파일 상태 기록 (작업 전 상태가 마지막 기록과 같았을 때만)
+    fn record_fingerprint(&mut self) {
+        if !std::mem::take(&mut self.fingerprint_matched) {
+            return;
+        }
+        if let Some((path, _)) = &self.current {
+            self.last_seen = Some((path.clone(), FileFingerprint::of(path)));
+        }
+    }
+
+    fn changed_since_last_seen(&self, path: &Path) -> bool {
+        matches!(&self.last_seen, Some((p, last)) if p == path && *last != FileFingerprint::of(path))
+    }
+
+    /// 마지막 작업 이후 파일이 바뀌었는지 확인 (작업 전 확인에서 발견한 변경 포함)
+    /// 바뀌었으면 교체된 파일을 다시 열도록 연결을 닫음
+    fn external_change(&mut self, path: &Path) -> bool {
+        let pending = self.pending_change.as_deref() == Some(path);
+        if pending {
+            self.pending_change = None;
+        }
+        let changed = pending || self.changed_since_last_seen(path);
+        let mut seen = FileFingerprint::of(path);
+        if changed && self.current.as_ref().is_some_and(|(p, _)| p == path) {
+            self.current = None;
+            // 연결을 닫으면서 생긴 체크포인트는 변경으로 보지 않음
+            seen = FileFingerprint::of(path);
+        }
+        self.last_seen = Some((path.to_path_buf(), seen));
+        changed
+    }
+
+    /// DB 파일을 다른 파일로 교체 (연결을 닫고 이름 변경, 새 파일의 암호 등록)
+    fn replace_file(
+        &mut self,
+        path: &Path,
+        replacement: &Path,
+        passphrase: Option<String>,
+    ) -> AppResult<()> {
+        if self.current.as_ref().is_some_and(|(p, _)| p == path) {
+            self.current = None;
+        }
+        // 이전 파일의 WAL이 새 파일에 적용되지 않도록 삭제
+        remove_sidecar_files(path)?;
+        fs::rename(replacement, path)?;
+
+        if let Ok(mut passphrases) = self.passphrases.lock() {
+            match passphrase {
+                Some(passphrase) => passphrases.insert(path.to_path_buf(), passphrase),
+                None => passphrases.remove(path),
+            };
+        }
+        // 앱이 교체한 파일은 외부 변경으로 보지 않음
+        self.last_seen = Some((path.to_path_buf(), FileFingerprint::of(path)));
+        Ok(())
+    }
+}
+
+/// 연결 생성 (WAL 모드, 외래 키, statement 캐시 설정)
+/// 읽기 전용 연결은 파일을 바꾸지 않도록 저널 모드를 설정하지 않음
+/// 암호화된 DB는 암호가 등록되어 있어야 열 수 있음
+fn open(path: &Path, read_only: bool, passphrase: Option<&str>) -> AppResult<Connection> {
+    if passphrase.is_none() && crypto::is_encrypted(path) {
+        return Err(AppError::PassphraseRequired);
+    }
+    let conn = if read_only {
+        Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?
+    } else {
+        Connection::open(path)?
+    };
+    if let Some(passphrase) = passphrase {
+        crypto::apply_key(&conn, passphrase)?;
+    }
+    conn.execute("PRAGMA foreign_keys = ON", [])?;
+    if !read_only {
+        conn.query_row("PRAGMA journal_mode = WAL", [], |row| {
+            row.get::<_, String>(0)
+        })?;
+    }
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+    Ok(conn)
+}
+
+/// DB 작업 스레드
+///
+/// 연결 하나를 전용 스레드에서 유지하고 작업을 순서대로 실행한다.
+/// 커맨드는 `run`으로 결과를 기다리므로 Tauri 메인 스레드를 막지 않는다.
+pub struct DbWorker {
+    sender: mpsc::Sender<Job>,
+    read_only_path: ReadOnlyPath,
+    passphrases: Passphrases,
+}
+
+impl DbWorker {
+    pub fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let read_only_path = ReadOnlyPath::default();
+        let passphrases = Passphrases::default();
+        let shared = (read_only_path.clone(), passphrases.clone());
+        thread::Builder::new()
+            .name("db-worker".to_string())
+            .spawn(move || {
+                let mut connection = WorkerConnection::new(shared.0.clone(), shared.1.clone());
+                for job in receiver {
+                    // 작업 중 panic이 나도 스레드는 유지 (연결은 다시 생성)
+                    if panic::catch_unwind(AssertUnwindSafe(|| job(&mut connection))).is_err() {
+                        log::error!("[DB] Job panicked, resetting connection");
+                        connection = WorkerConnection::new(shared.0.clone(), shared.1.clone());
+                    }
+                    connection.record_fingerprint();
+                }
+            })
+            .expect("failed to spawn db worker thread");
+
+        Self {
+            sender,
+            read_only_path,
+            passphrases,
+        }
+    }
+
+    /// 읽기 전용으로 열 DB 경로 설정 (None이면 모두 쓰기 가능, 다음 작업부터 다시 연결)
+    pub fn set_read_only_path(&self, path: Option<PathBuf>) {
+        if let Ok(mut read_only_path) = self.read_only_path.lock() {
+            *read_only_path = path;
+        }
+    }
+
+    /// 해당 DB를 읽기 전용으로 여는지 여부
+    pub fn is_read_only(&self, path: &Path) -> bool {
+        self.read_only_path
+            .lock()
+            .map(|p| p.as_deref() == Some(path))
+            .unwrap_or(false)
+    }
+
+    /// DB 암호 등록/삭제 (다음 작업부터 해당 암호로 다시 연결)
+    pub fn set_passphrase(&self, path: &Path, passphrase: Option<String>) {
+        if let Ok(mut passphrases) = self.passphrases.lock() {
+            match passphrase {
+                Some(passphrase) => passphrases.insert(path.to_path_buf(), passphrase),
+                None => passphrases.remove(path),
+            };
+        }
+    }
+
+    /// 등록된 DB 암호
+    pub fn passphrase(&self, path: &Path) -> Option<String> {
+        self.passphrases.lock().ok()?.get(path).cloned()
+    }
+
+    /// 암호화된 DB인데 암호가 등록되지 않음 (잠금 해제 필요)
+    pub fn needs_passphrase(&self, path: &Path) -> bool {
+        crypto::is_encrypted(path) && self.passphrase(path).is_none()
+    }
+
+    /// 등록된 암호 모두 삭제 (로그아웃)
+    pub fn clear_passphrases(&self) {
+        if let Ok(mut passphrases) = self.passphrases.lock() {
+            passphrases.clear();
+        }
+    }
+
+    fn submit<T, F, R>(&self, path: PathBuf, f: F, reply: R) -> AppResult<()>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+        R: FnOnce(AppResult<T>) + Send + 'static,
+    {
+        let job: Job = Box::new(move |connection| {
+            reply(connection.get(&path).and_then(f));
+        });
+        self.sender
+            .send(job)
+            .map_err(|_| AppError::Internal("DB 작업 스레드가 종료되었습니다.".to_string()))
+    }
+
+    /// 작업 실행 후 결과 대기 (비동기, 커맨드용)
+    pub async fn run<T, F>(&self, path: PathBuf, f: F) -> AppResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.submit(path, f, move |result| {
+            let _ = tx.send(result);
+        })?;
+        rx.await
+            .map_err(|_| AppError::Internal("DB 작업이 취소되었습니다.".to_string()))?
+    }
+
+    /// 마지막 작업 이후 DB 파일이 외부에서 바뀌었는지 확인 (동기화 폴더의 파일 교체 등)
+    /// 작업 순서대로 실행되므로 앱 자체의 쓰기와 겹치지 않음
+    pub async fn detect_external_change(&self, path: PathBuf) -> AppResult<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(Box::new(move |connection| {
+                let _ = tx.send(connection.external_change(&path));
+            }))
+            .map_err(|_| AppError::Internal("DB 작업 스레드가 종료되었습니다.".to_string()))?;
+        rx.await
+            .map_err(|_| AppError::Internal("DB 작업이 취소되었습니다.".to_string()))
+    }
+
+    /// DB 파일을 다른 파일로 교체 (암호화, 암호 변경, 암호화된 DB의 백업 복원)
+    /// 작업 스레드에서 연결을 닫은 뒤 교체하므로 다른 작업과 겹치지 않음
+    pub async fn replace_file(
+        &self,
+        path: PathBuf,
+        replacement: PathBuf,
+        passphrase: Option<String>,
+    ) -> AppResult<()> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(Box::new(move |connection| {
+                let _ = tx.send(connection.replace_file(&path, &replacement, passphrase));
+            }))
+            .map_err(|_| AppError::Internal("DB 작업 스레드가 종료되었습니다.".to_string()))?;
+        rx.await
+            .map_err(|_| AppError::Internal("DB 작업이 취소되었습니다.".to_string()))?
+    }
+
+    /// 연결 닫기 (로그아웃 등, 다음 작업 시 다시 연결)
+    pub fn close(&self) {
+        let _ = self.sender.send(Box::new(|connection| {
+            if let Some((path, conn)) = connection.current.take() {
+                drop(conn);
+                // 닫으면서 생긴 체크포인트는 외부 변경으로 보지 않음
+                connection.last_seen = Some((path.clone(), FileFingerprint::of(&path)));
+                log::info!("[DB] Connection closed");
+            }
+        }));
+    }
+}
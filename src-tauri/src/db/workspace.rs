@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use chrono::Utc;
+
+use super::models::{AppConfig, Workspace};
+use crate::error::{AppError, AppResult};
+
+fn now() -> String {
+    Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// 파일명으로 만든 기본 이름 (mirumi.db → mirumi)
+fn default_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "mirumi".to_string())
+}
+
+fn validate_name(name: &str) -> AppResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::InvalidInput(
+            "워크스페이스 이름을 입력해주세요.".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn not_found() -> AppError {
+    AppError::NotFound("워크스페이스를 찾을 수 없습니다.".to_string())
+}
+
+/// 이전 버전 config.json(db_path만 있음)을 워크스페이스로 옮김 (바뀌었으면 true)
+pub fn migrate_legacy_path(config: &mut AppConfig) -> bool {
+    if !config.workspaces.is_empty() {
+        return false;
+    }
+    let Some(path) = config.db_path.clone() else {
+        return false;
+    };
+    activate_path(config, Path::new(&path));
+    true
+}
+
+/// 경로를 활성 워크스페이스로 설정 (등록되지 않은 경로면 파일명으로 추가)
+pub fn activate_path(config: &mut AppConfig, path: &Path) -> Workspace {
+    let path = path.to_string_lossy().to_string();
+    let index = match config.workspaces.iter().position(|w| w.path == path) {
+        Some(index) => index,
+        None => {
+            config.workspaces.push(Workspace {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: default_name(Path::new(&path)),
+                path: path.clone(),
+                last_opened_at: None,
+            });
+            config.workspaces.len() - 1
+        }
+    };
+
+    let workspace = &mut config.workspaces[index];
+    workspace.last_opened_at = Some(now());
+    config.active_workspace_id = Some(workspace.id.clone());
+    config.db_path = Some(path);
+    workspace.clone()
+}
+
+/// 워크스페이스 경로 변경 (DB 이동 시 이름과 id는 유지)
+pub fn relocate(config: &mut AppConfig, from: &Path, to: &Path) {
+    let from = from.to_string_lossy();
+    for workspace in config.workspaces.iter_mut().filter(|w| w.path == from) {
+        workspace.path = to.to_string_lossy().to_string();
+    }
+}
+
+/// 워크스페이스 추가 (같은 경로는 한 번만 등록)
+pub fn add(config: &mut AppConfig, name: &str, path: &Path) -> AppResult<Workspace> {
+    let name = validate_name(name)?;
+    let path = path.to_string_lossy().to_string();
+    if config.workspaces.iter().any(|w| w.path == path) {
+        return Err(AppError::InvalidInput("이미 등록된 DB입니다.".to_string()));
+    }
+
+    let workspace = Workspace {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        path,
+        last_opened_at: None,
+    };
+    config.workspaces.push(workspace.clone());
+    Ok(workspace)
+}
+
+pub fn find<'a>(config: &'a AppConfig, id: &str) -> AppResult<&'a Workspace> {
+    config
+        .workspaces
+        .iter()
+        .find(|w| w.id == id)
+        .ok_or_else(not_found)
+}
+
+pub fn rename(config: &mut AppConfig, id: &str, name: &str) -> AppResult<Workspace> {
+    let name = validate_name(name)?;
+    let workspace = config
+        .workspaces
+        .iter_mut()
+        .find(|w| w.id == id)
+        .ok_or_else(not_found)?;
+    workspace.name = name;
+    Ok(workspace.clone())
+}
+
+/// 워크스페이스 목록에서 제거 (DB 파일은 삭제하지 않음)
+pub fn remove(config: &mut AppConfig, id: &str) -> AppResult<()> {
+    if config.active_workspace_id.as_deref() == Some(id) {
+        return Err(AppError::InvalidInput(
+            "사용 중인 워크스페이스는 삭제할 수 없습니다.".to_string(),
+        ));
+    }
+    let before = config.workspaces.len();
+    config.workspaces.retain(|w| w.id != id);
+    if config.workspaces.len() == before {
+        return Err(not_found());
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::path::Path;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+/// 커맨드 공통 결과 타입
+pub type AppResult<T> = Result<T, AppError>;
+
+/// 커맨드 에러
+///
+/// 프론트엔드에는 `{ code, message, details }` 형태로 전달된다.
+/// `code`는 화면 분기용 고정 값이고 `message`는 사용자에게 표시할 문장,
+/// `details`는 원본 에러 메시지 등 디버깅용 정보이다.
+#[derive(Debug)]
+pub enum AppError {
+    /// DB 경로가 설정되지 않음 (온보딩 필요)
+    DbNotConfigured,
+    /// 설정된 DB 파일이 없음 (details: 경로)
+    DbFileNotFound(String),
+    /// 다른 인스턴스가 사용 중인 DB (details: 잠금 보유자)
+    DbLocked(String),
+    /// 하트비트가 끊긴 잠금 (details: 잠금 보유자, 확인 후 가져올 수 있음)
+    DbLockStale(String),
+    /// 읽기 전용으로 연 DB에 쓰기 시도
+    ReadOnly,
+    /// 암호화된 DB인데 암호가 입력되지 않음
+    PassphraseRequired,
+    /// DB 암호가 틀림
+    WrongPassphrase,
+    /// 앱보다 최신 스키마의 DB (앱 업데이트 필요)
+    UnsupportedSchema { db_version: u32, app_version: u32 },
+    /// 마이그레이션 없이 읽을 수 없는 스키마 (details: 없는 테이블/컬럼)
+    UnreadableSchema(String),
+    /// 요청한 데이터가 없음 (message)
+    NotFound(String),
+    /// 잘못된 입력값 (message)
+    InvalidInput(String),
+    /// 외래 키/UNIQUE 등 제약 조건 위반 (details: SQLite 메시지)
+    Constraint(String),
+    /// 그 외 SQLite 오류 (details: SQLite 메시지)
+    Database(String),
+    /// 파일 입출력 오류 (details: 원본 메시지)
+    Io(String),
+    /// 내부 오류 (message)
+    Internal(String),
+}
+
+impl AppError {
+    pub fn db_file_not_found(path: &Path) -> Self {
+        Self::DbFileNotFound(path.to_string_lossy().to_string())
+    }
+
+    /// 프론트엔드 분기용 에러 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DbNotConfigured => "DB_NOT_CONFIGURED",
+            Self::DbFileNotFound(_) => "DB_FILE_NOT_FOUND",
+            Self::DbLocked(_) => "DB_LOCKED",
+            Self::DbLockStale(_) => "DB_LOCK_STALE",
+            Self::ReadOnly => "DB_READ_ONLY",
+            Self::PassphraseRequired => "PASSPHRASE_REQUIRED",
+            Self::WrongPassphrase => "WRONG_PASSPHRASE",
+            Self::UnsupportedSchema { .. } => "UNSUPPORTED_SCHEMA",
+            Self::UnreadableSchema(_) => "UNREADABLE_SCHEMA",
+            Self::NotFound(_) => "NOT_FOUND",
+            Self::InvalidInput(_) => "INVALID_INPUT",
+            Self::Constraint(_) => "CONSTRAINT_VIOLATION",
+            Self::Database(_) => "DATABASE_ERROR",
+            Self::Io(_) => "IO_ERROR",
+            Self::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// 사용자에게 표시할 메시지
+    pub fn message(&self) -> String {
+        match self {
+            Self::DbNotConfigured => "DB가 설정되지 않았습니다.".to_string(),
+            Self::DbFileNotFound(_) => "DB 파일이 존재하지 않습니다.".to_string(),
+            Self::DbLocked(_) => {
+                "다른 곳에서 사용 중인 DB입니다. 읽기 전용으로만 열 수 있습니다.".to_string()
+            }
+            Self::DbLockStale(_) => {
+                "응답이 없는 다른 앱이 DB를 잠그고 있습니다. 확인 후 잠금을 가져올 수 있습니다."
+                    .to_string()
+            }
+            Self::ReadOnly => "읽기 전용으로 연 DB는 수정할 수 없습니다.".to_string(),
+            Self::PassphraseRequired => "암호화된 DB입니다. 암호를 입력해주세요.".to_string(),
+            Self::WrongPassphrase => "DB 암호가 올바르지 않습니다.".to_string(),
+            Self::UnsupportedSchema { .. } => {
+                "더 최신 버전의 앱에서 사용한 DB입니다. 앱을 업데이트한 뒤 다시 열어주세요."
+                    .to_string()
+            }
+            Self::UnreadableSchema(_) => "앱에서 읽을 수 없는 형식의 DB입니다.".to_string(),
+            Self::NotFound(message) | Self::InvalidInput(message) | Self::Internal(message) => {
+                message.clone()
+            }
+            Self::Constraint(_) => "데이터 제약 조건을 위반했습니다.".to_string(),
+            Self::Database(_) => "DB 오류가 발생했습니다.".to_string(),
+            Self::Io(_) => "파일 처리 중 오류가 발생했습니다.".to_string(),
+        }
+    }
+
+    /// 디버깅용 상세 정보
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::DbFileNotFound(details)
+            | Self::DbLocked(details)
+            | Self::DbLockStale(details)
+            | Self::UnreadableSchema(details)
+            | Self::Constraint(details)
+            | Self::Database(details)
+            | Self::Io(details) => Some(details.clone()),
+            Self::UnsupportedSchema {
+                db_version,
+                app_version,
+            } => Some(format!(
+                "DB schema v{}, supported v{}",
+                db_version, app_version
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.details() {
+            Some(details) => write!(f, "[{}] {} ({})", self.code(), self.message(), details),
+            None => write!(f, "[{}] {}", self.code(), self.message()),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.message())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(e: rusqlite::Error) -> Self {
+        match &e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                Self::NotFound("데이터를 찾을 수 없습니다.".to_string())
+            }
+            rusqlite::Error::SqliteFailure(error, _)
+                if error.code == rusqlite::ErrorCode::ConstraintViolation =>
+            {
+                Self::Constraint(e.to_string())
+            }
+            // 읽기 전용 연결에서 쓰기 시도 (커맨드 외부의 타이머 기록 등)
+            rusqlite::Error::SqliteFailure(error, _)
+                if error.code == rusqlite::ErrorCode::ReadOnly =>
+            {
+                Self::ReadOnly
+            }
+            _ => Self::Database(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Internal(e.to_string())
+    }
+}
+
+impl<T> From<std::sync::PoisonError<T>> for AppError {
+    fn from(e: std::sync::PoisonError<T>) -> Self {
+        Self::Internal(e.to_string())
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(e: tauri::Error) -> Self {
+        Self::Internal(e.to_string())
+    }
+}
@@ -0,0 +1,94 @@
+use serde_json::Value;
+use tauri::{AppHandle, State};
+
+use super::{SettingEntry, SettingScope};
+use crate::db::{load_config, save_config, DbState};
+use crate::error::{AppError, AppResult};
+
+/// 설정 조회 (없거나 잘못된 값은 기본값)
+#[tauri::command]
+pub async fn get_setting(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    key: String,
+) -> AppResult<Value> {
+    let def = super::find(&key)?;
+    match def.scope {
+        SettingScope::Machine => {
+            let config = load_config(&app_handle)?;
+            let legacy = if config.settings.contains_key(def.key) {
+                None
+            } else {
+                state
+                    .run(&app_handle, move |conn| {
+                        Ok(super::legacy_raw(conn, def.key))
+                    })
+                    .await
+                    .ok()
+                    .flatten()
+            };
+            Ok(super::machine_value(
+                def,
+                &config.settings,
+                legacy.as_deref(),
+            ))
+        }
+        SettingScope::Workspace => {
+            state
+                .run(&app_handle, move |conn| Ok(super::get(conn, def.key)))
+                .await
+        }
+    }
+}
+
+/// 설정 저장 후 `setting-changed` 전송 (등록되지 않은 키나 잘못된 값은 INVALID_INPUT)
+#[tauri::command]
+pub async fn set_setting(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+    key: String,
+    value: Value,
+) -> AppResult<()> {
+    let def = super::find(&key)?;
+    def.validate(&value)?;
+
+    match def.scope {
+        SettingScope::Machine => {
+            let mut config = load_config(&app_handle)?;
+            config.settings.insert(def.key.to_string(), value.clone());
+            save_config(&app_handle, &config)?;
+        }
+        SettingScope::Workspace => {
+            let stored = value.clone();
+            state
+                .run_mut(&app_handle, move |conn| super::write(conn, def, &stored))
+                .await?;
+        }
+    }
+
+    super::broadcast(&app_handle, def, value).await;
+    Ok(())
+}
+
+/// 전체 설정 조회 (등록된 설정만, 타입에 맞는 값으로 반환)
+/// DB가 없거나 열 수 없으면 워크스페이스 설정은 기본값으로 채움
+#[tauri::command]
+pub async fn get_all_settings(
+    app_handle: AppHandle,
+    state: State<'_, DbState>,
+) -> AppResult<Vec<SettingEntry>> {
+    let machine = load_config(&app_handle)?.settings;
+    let from_db = machine.clone();
+    let result = state
+        .run(&app_handle, move |conn| {
+            super::entries(&from_db, Some(conn))
+        })
+        .await;
+
+    match result {
+        Err(
+            AppError::DbNotConfigured | AppError::DbFileNotFound(_) | AppError::PassphraseRequired,
+        ) => super::entries(&machine, None),
+        result => result,
+    }
+}
@@ -0,0 +1,468 @@
+pub mod commands;
+
+pub use commands::*;
+
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use crate::db::load_config;
+use crate::db::models::AppConfig;
+use crate::error::{AppError, AppResult};
+use crate::timer;
+
+/// 설정 저장 위치
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SettingScope {
+    /// 기기별 설정 (config.json, DB를 바꿔도 유지)
+    Machine,
+    /// 워크스페이스별 설정 (DB의 tbl_setting, DB 파일과 함께 이동)
+    Workspace,
+}
+
+/// 설정값 타입과 검증 규칙
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SettingKind {
+    Bool,
+    /// min 이상 max 이하 정수
+    Int {
+        min: i64,
+        max: i64,
+    },
+    /// options 중 하나인 문자열
+    Choice {
+        options: &'static [&'static str],
+    },
+    /// 각 항목이 min 이상 max 이하인 정수 목록
+    IntList {
+        min: i64,
+        max: i64,
+    },
+}
+
+/// 설정 정의
+#[derive(Debug, Clone, Copy)]
+pub struct SettingDef {
+    pub key: &'static str,
+    pub kind: SettingKind,
+    pub scope: SettingScope,
+    /// 기본값 (JSON)
+    pub default: &'static str,
+}
+
+/// 설정 목록
+///
+/// 새 설정은 여기에 추가한다. 워크스페이스 설정은 마이그레이션 시 기본값이 tbl_setting에 삽입된다.
+pub const REGISTRY: &[SettingDef] = &[
+    SettingDef {
+        key: "theme",
+        kind: SettingKind::Choice {
+            options: &["system", "light", "dark"],
+        },
+        scope: SettingScope::Machine,
+        default: r#""system""#,
+    },
+    SettingDef {
+        key: "language",
+        kind: SettingKind::Choice {
+            options: &["ko", "en"],
+        },
+        scope: SettingScope::Machine,
+        default: r#""ko""#,
+    },
+    SettingDef {
+        // 여러 타이머 실행 중 트레이에 표시할 타이머 (최근 시작 / 가장 먼저 종료)
+        key: "tray_display_mode",
+        kind: SettingKind::Choice {
+            options: &["recent", "soonest"],
+        },
+        scope: SettingScope::Machine,
+        default: r#""recent""#,
+    },
+    SettingDef {
+        key: "timer_default_minutes",
+        kind: SettingKind::Int { min: 1, max: 1440 },
+        scope: SettingScope::Workspace,
+        default: "5",
+    },
+    SettingDef {
+        key: "notification_sound",
+        kind: SettingKind::Bool,
+        scope: SettingScope::Workspace,
+        default: "true",
+    },
+    SettingDef {
+        key: "notification_vibration",
+        kind: SettingKind::Bool,
+        scope: SettingScope::Workspace,
+        default: "true",
+    },
+    SettingDef {
+        key: "timer_resume_on_launch",
+        kind: SettingKind::Bool,
+        scope: SettingScope::Workspace,
+        default: "true",
+    },
+    SettingDef {
+        key: "timer_overtime_enabled",
+        kind: SettingKind::Bool,
+        scope: SettingScope::Workspace,
+        default: "false",
+    },
+    SettingDef {
+        // 종료 전 알림 시점 (분, 0은 종료 시점)
+        key: "timer_warning_minutes",
+        kind: SettingKind::IntList { min: 0, max: 1440 },
+        scope: SettingScope::Workspace,
+        default: "[5,1,0]",
+    },
+    SettingDef {
+        // 타이머 시작 시 사이클 모드(집중/휴식 자동 전환) 사용
+        key: "timer_cycle_enabled",
+        kind: SettingKind::Bool,
+        scope: SettingScope::Workspace,
+        default: "false",
+    },
+    SettingDef {
+        key: "pomodoro_focus_minutes",
+        kind: SettingKind::Int { min: 1, max: 240 },
+        scope: SettingScope::Workspace,
+        default: "25",
+    },
+    SettingDef {
+        // 0이면 짧은 휴식 건너뜀
+        key: "pomodoro_short_break_minutes",
+        kind: SettingKind::Int { min: 0, max: 120 },
+        scope: SettingScope::Workspace,
+        default: "5",
+    },
+    SettingDef {
+        // 0이면 긴 휴식 건너뜀
+        key: "pomodoro_long_break_minutes",
+        kind: SettingKind::Int { min: 0, max: 240 },
+        scope: SettingScope::Workspace,
+        default: "15",
+    },
+    SettingDef {
+        key: "pomodoro_intervals",
+        kind: SettingKind::Int { min: 1, max: 20 },
+        scope: SettingScope::Workspace,
+        default: "4",
+    },
+    SettingDef {
+        // 0이면 예약 백업 안 함
+        key: "backup_interval_hours",
+        kind: SettingKind::Int { min: 0, max: 720 },
+        scope: SettingScope::Workspace,
+        default: "24",
+    },
+    SettingDef {
+        // 백업 종류별 보관 개수
+        key: "backup_keep_count",
+        kind: SettingKind::Int { min: 1, max: 100 },
+        scope: SettingScope::Workspace,
+        default: "7",
+    },
+];
+
+/// 설정값 (get_all_settings)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingEntry {
+    pub key: &'static str,
+    pub value: Value,
+    pub default_value: Value,
+    pub kind: SettingKind,
+    pub scope: SettingScope,
+    /// 워크스페이스 설정의 마지막 변경 시각 (UTC, 저장된 적 없거나 기기별 설정이면 None)
+    pub updated_at: Option<String>,
+}
+
+/// 설정 변경 이벤트 페이로드 (`setting-changed`)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingChangedPayload {
+    pub key: &'static str,
+    pub value: Value,
+    pub scope: SettingScope,
+}
+
+/// 키로 설정 정의 찾기
+pub fn find(key: &str) -> AppResult<&'static SettingDef> {
+    REGISTRY
+        .iter()
+        .find(|def| def.key == key)
+        .ok_or_else(|| AppError::InvalidInput(format!("알 수 없는 설정입니다: {}", key)))
+}
+
+impl SettingKind {
+    fn check(&self, value: &Value) -> Result<(), String> {
+        let in_range =
+            |v: &Value, min: i64, max: i64| v.as_i64().is_some_and(|n| (min..=max).contains(&n));
+        let valid = match self {
+            Self::Bool => value.is_boolean(),
+            Self::Int { min, max } => in_range(value, *min, *max),
+            Self::Choice { options } => value.as_str().is_some_and(|s| options.contains(&s)),
+            Self::IntList { min, max } => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(|v| in_range(v, *min, *max))),
+        };
+        if valid {
+            return Ok(());
+        }
+
+        Err(match self {
+            Self::Bool => "true 또는 false여야 합니다.".to_string(),
+            Self::Int { min, max } => format!("{}~{} 사이의 정수여야 합니다.", min, max),
+            Self::Choice { options } => format!("{} 중 하나여야 합니다.", options.join(", ")),
+            Self::IntList { min, max } => {
+                format!("{}~{} 사이 정수의 목록이어야 합니다.", min, max)
+            }
+        })
+    }
+}
+
+impl SettingDef {
+    pub fn default_value(&self) -> Value {
+        serde_json::from_str(self.default).unwrap_or(Value::Null)
+    }
+
+    /// 값 검증 (잘못된 값이면 INVALID_INPUT)
+    pub fn validate(&self, value: &Value) -> AppResult<()> {
+        self.kind.check(value).map_err(|reason| {
+            AppError::InvalidInput(format!(
+                "'{}' 설정값이 올바르지 않습니다. {}",
+                self.key, reason
+            ))
+        })
+    }
+
+    /// 저장된 문자열을 값으로 변환 (없거나 잘못된 값은 None)
+    ///
+    /// 이전 버전에서 저장한 형식(따옴표 없는 문자열, JSON 문자열로 감싼 값,
+    /// 쉼표로 구분한 목록)도 읽는다.
+    fn parse_stored(&self, raw: &str) -> Option<Value> {
+        let raw = raw.trim();
+        let mut candidates = Vec::new();
+        match serde_json::from_str::<Value>(raw) {
+            Ok(Value::String(inner)) => {
+                candidates.push(serde_json::from_str(&inner).ok());
+                candidates.push(Some(Value::String(inner)));
+            }
+            Ok(value) => candidates.push(Some(value)),
+            Err(_) => candidates.push(Some(Value::String(raw.to_string()))),
+        }
+        if let SettingKind::IntList { .. } = self.kind {
+            let items: Option<Vec<Value>> = raw
+                .trim_matches(|c| c == '"' || c == '[' || c == ']')
+                .split(',')
+                .map(|item| item.trim().parse::<i64>().ok().map(Value::from))
+                .collect();
+            candidates.push(items.map(Value::Array));
+        }
+
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|value| self.kind.check(value).is_ok())
+    }
+
+    /// 저장된 값 또는 기본값
+    fn resolve(&self, stored: Option<&str>) -> Value {
+        stored
+            .and_then(|raw| {
+                let value = self.parse_stored(raw);
+                if value.is_none() {
+                    log::warn!("[Settings] Invalid stored value for {}: {}", self.key, raw);
+                }
+                value
+            })
+            .unwrap_or_else(|| self.default_value())
+    }
+}
+
+// ============================================================================
+// 워크스페이스 설정 (tbl_setting)
+// ============================================================================
+
+/// 저장된 설정 행 (키 → (값, 변경 시각))
+/// 설정 테이블이 아직 없는 DB(마이그레이션 전)는 빈 목록
+fn read_rows(conn: &Connection) -> AppResult<BTreeMap<String, (Option<String>, String)>> {
+    let mut stmt = match conn.prepare_cached("SELECT key, value, updated_at FROM tbl_setting") {
+        Ok(stmt) => stmt,
+        Err(_) => return Ok(BTreeMap::new()),
+    };
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+        .collect::<Result<_, _>>()?;
+    Ok(rows)
+}
+
+fn read_raw(conn: &Connection, key: &str) -> Option<String> {
+    conn.query_row(
+        "SELECT value FROM tbl_setting WHERE key = ?1",
+        [key],
+        |row| row.get::<_, Option<String>>(0),
+    )
+    .ok()
+    .flatten()
+}
+
+/// 워크스페이스 설정값 (없거나 잘못된 값은 기본값)
+pub fn get(conn: &Connection, key: &str) -> Value {
+    match find(key) {
+        Ok(def) => def.resolve(read_raw(conn, key).as_deref()),
+        Err(_) => Value::Null,
+    }
+}
+
+pub fn get_bool(conn: &Connection, key: &str) -> bool {
+    get(conn, key).as_bool().unwrap_or(false)
+}
+
+pub fn get_int(conn: &Connection, key: &str) -> i64 {
+    get(conn, key).as_i64().unwrap_or(0)
+}
+
+pub fn get_int_list(conn: &Connection, key: &str) -> Vec<i64> {
+    get(conn, key)
+        .as_array()
+        .map(|items| items.iter().filter_map(Value::as_i64).collect())
+        .unwrap_or_default()
+}
+
+/// 워크스페이스 설정 저장 (JSON 문자열)
+pub fn write(conn: &Connection, def: &SettingDef, value: &Value) -> AppResult<()> {
+    conn.execute(
+        r#"
+        INSERT INTO tbl_setting (id, key, value, updated_at)
+        VALUES ('setting_' || ?1, ?1, ?2, datetime('now'))
+        ON CONFLICT(key) DO UPDATE SET value = ?2, updated_at = datetime('now')
+        "#,
+        rusqlite::params![def.key, value.to_string()],
+    )?;
+    Ok(())
+}
+
+/// 기본값 삽입 (이미 있는 값은 유지)
+pub fn seed_defaults(conn: &Connection) -> AppResult<()> {
+    for def in REGISTRY
+        .iter()
+        .filter(|def| def.scope == SettingScope::Workspace)
+    {
+        conn.execute(
+            r#"
+            INSERT OR IGNORE INTO tbl_setting (id, key, value, updated_at)
+            VALUES ('setting_' || ?1, ?1, ?2, datetime('now'))
+            "#,
+            rusqlite::params![def.key, def.default],
+        )
+        .map_err(|e| AppError::Database(format!("Failed to seed setting '{}': {}", def.key, e)))?;
+    }
+    Ok(())
+}
+
+/// 전체 설정값
+///
+/// 기기별 설정은 config.json 값을 쓰고, 아직 저장하지 않았으면 이전 버전이 DB에 저장한 값을 사용한다.
+/// DB를 열 수 없으면(`conn`이 None) 워크스페이스 설정은 기본값으로 채운다.
+pub fn entries(
+    machine: &BTreeMap<String, Value>,
+    conn: Option<&Connection>,
+) -> AppResult<Vec<SettingEntry>> {
+    let rows = match conn {
+        Some(conn) => read_rows(conn)?,
+        None => BTreeMap::new(),
+    };
+
+    Ok(REGISTRY
+        .iter()
+        .map(|def| {
+            let row = rows.get(def.key);
+            let stored_raw = row.and_then(|(value, _)| value.clone());
+            let (value, updated_at) = match def.scope {
+                SettingScope::Machine => (machine_value(def, machine, stored_raw.as_deref()), None),
+                SettingScope::Workspace => (
+                    def.resolve(stored_raw.as_deref()),
+                    row.map(|(_, updated_at)| updated_at.clone()),
+                ),
+            };
+            SettingEntry {
+                key: def.key,
+                value,
+                default_value: def.default_value(),
+                kind: def.kind,
+                scope: def.scope,
+                updated_at,
+            }
+        })
+        .collect())
+}
+
+/// 기기별 설정값 (config.json → 이전 버전의 DB 값 → 기본값)
+pub fn machine_value(
+    def: &SettingDef,
+    machine: &BTreeMap<String, Value>,
+    legacy_raw: Option<&str>,
+) -> Value {
+    match machine.get(def.key) {
+        Some(value) if def.kind.check(value).is_ok() => value.clone(),
+        _ => def.resolve(legacy_raw),
+    }
+}
+
+/// config.json의 기기별 설정값 (config.json을 읽을 수 없으면 기본값)
+pub fn machine_setting(app_handle: &AppHandle, key: &str) -> Value {
+    let Ok(def) = find(key) else {
+        return Value::Null;
+    };
+    let machine = load_config(app_handle)
+        .map(|config| config.settings)
+        .unwrap_or_default();
+    machine_value(def, &machine, None)
+}
+
+/// 설정 변경 알림
+///
+/// 모든 창(main, task-*, task-widget)에 `setting-changed`를 보내고,
+/// 설정을 캐시하는 타이머 엔진과 트레이에 변경을 반영한다.
+pub async fn broadcast(app_handle: &AppHandle, def: &SettingDef, value: Value) {
+    timer::apply_setting(app_handle, def.key, &value).await;
+    let _ = app_handle.emit(
+        "setting-changed",
+        SettingChangedPayload {
+            key: def.key,
+            value,
+            scope: def.scope,
+        },
+    );
+}
+
+/// config.json의 이전 theme/language 필드를 settings로 옮김 (옮긴 값이 있으면 true)
+pub fn migrate_legacy_config(config: &mut AppConfig) -> bool {
+    let legacy = [
+        ("theme", config.theme.take()),
+        ("language", config.language.take()),
+    ];
+    let mut migrated = false;
+    for (key, value) in legacy {
+        let Some(value) = value else { continue };
+        if !config.settings.contains_key(key) {
+            config
+                .settings
+                .insert(key.to_string(), Value::String(value));
+        }
+        migrated = true;
+    }
+    migrated
+}
+
+/// 이전 버전의 DB 값 (기기별 설정을 config.json에 저장하기 전)
+pub fn legacy_raw(conn: &Connection, key: &str) -> Option<String> {
+    read_raw(conn, key)
+}
@@ -0,0 +1,184 @@
+use chrono::Utc;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::cycle::TimerPhase;
+use super::persist::{self, read_db, write_db};
+use super::{emit_stopped, SharedTimerState, TaskTimer, TimerMode, TimerState};
+use crate::db::ensure_writable;
+use crate::db::timer_store;
+use crate::error::{AppError, AppResult};
+use crate::tray::{refresh_menu, update_tray};
+
+// 웹뷰 없이 실행하는 task 동작 (트레이 메뉴 등)
+// 메인 화면의 상태 변경과 같은 DB 기록을 남기고 `tasks-changed`로 화면에 알림
+// 읽기 전용 DB면 먼저 거부하고, DB에 기록한 뒤 타이머를 바꿔 실패해도 타이머와 DB가 어긋나지 않게 함
+
+/// 일시정지
+pub async fn pause_task(app: &AppHandle, task_id: &str) -> AppResult<()> {
+    ensure_writable(app)?;
+    let remaining = remaining_of(shared_timer_state(app).lock().await.timers.get(task_id));
+
+    let id = task_id.to_string();
+    write_db(app, move |conn| {
+        timer_store::change_task_status(conn, &id, "PAUSED", remaining)
+    })
+    .await?;
+
+    let run_end = {
+        let timer_state = shared_timer_state(app);
+        let mut state = timer_state.lock().await;
+        stop_timer(app, &mut state, task_id).map(|t| persist::RunEnd::finish(&t, "paused"))
+    };
+    persist::finish_runs(app, run_end.into_iter().collect()).await;
+
+    notify_changed(app).await;
+    Ok(())
+}
+
+/// 재개 (다른 진행 중 task는 메인 화면과 같이 일시정지)
+pub async fn resume_task(app: &AppHandle, task_id: &str) -> AppResult<()> {
+    ensure_writable(app)?;
+    let tasks = read_db(app, |conn| timer_store::list_active_tasks(conn)).await?;
+    let task = tasks
+        .iter()
+        .find(|t| t.id == task_id)
+        .cloned()
+        .ok_or_else(|| AppError::NotFound("Task를 찾을 수 없습니다.".to_string()))?;
+    let others: Vec<String> = tasks
+        .iter()
+        .filter(|t| t.id != task_id && t.status == "IN_PROGRESS")
+        .map(|t| t.id.clone())
+        .collect();
+
+    let paused: Vec<(String, Option<i64>)> = {
+        let timer_state = shared_timer_state(app);
+        let state = timer_state.lock().await;
+        others
+            .iter()
+            .map(|id| (id.clone(), remaining_of(state.timers.get(id))))
+            .collect()
+    };
+
+    let id = task_id.to_string();
+    write_db(app, move |conn| {
+        for (other_id, remaining) in &paused {
+            timer_store::change_task_status(conn, other_id, "PAUSED", *remaining)?;
+        }
+        timer_store::change_task_status(conn, &id, "IN_PROGRESS", None)
+    })
+    .await?;
+
+    let run_ends: Vec<_> = {
+        let timer_state = shared_timer_state(app);
+        let mut state = timer_state.lock().await;
+        others
+            .iter()
+            .filter_map(|id| stop_timer(app, &mut state, id))
+            .map(|t| persist::RunEnd::finish(&t, "paused"))
+            .collect()
+    };
+    persist::finish_runs(app, run_ends).await;
+
+    match task.expected_duration {
+        // 예상 시간이 없으면 스톱워치
+        None => {
+            let run = persist::begin_run(app, task_id, TimerPhase::Focus, Utc::now()).await;
+            let timer_state = shared_timer_state(app);
+            let mut state = timer_state.lock().await;
+            state.start_stopwatch(task_id, &task.title, 0, run);
+        }
+        // 메인 화면의 Play와 같이 사이클 모드 설정을 따름
+        Some(minutes) => {
+            let remaining_secs = task
+                .remaining_time_seconds
+                .filter(|s| *s > 0)
+                .unwrap_or(minutes * 60)
+                .max(0) as u64;
+            let existing = shared_timer_state(app)
+                .lock()
+                .await
+                .timers
+                .get(task_id)
+                .cloned();
+            let (cycle, remaining_secs, mode) =
+                persist::resolve_countdown(app, existing.as_ref(), None, remaining_secs).await;
+            let phase = cycle.as_ref().map(|c| c.phase).unwrap_or_default();
+            let run = persist::begin_run(app, task_id, phase, Utc::now()).await;
+
+            let timer_state = shared_timer_state(app);
+            let mut state = timer_state.lock().await;
+            state.start(task_id, &task.title, remaining_secs, run, cycle, mode);
+        }
+    }
+
+    notify_changed(app).await;
+    Ok(())
+}
+
+/// 완료
+pub async fn complete_task(app: &AppHandle, task_id: &str) -> AppResult<()> {
+    ensure_writable(app)?;
+    let id = task_id.to_string();
+    write_db(app, move |conn| {
+        timer_store::change_task_status(conn, &id, "COMPLETED", Some(0))
+    })
+    .await?;
+
+    let run_end = {
+        let timer_state = shared_timer_state(app);
+        let mut state = timer_state.lock().await;
+        stop_timer(app, &mut state, task_id).map(|t| persist::RunEnd::finish(&t, "completed"))
+    };
+    persist::finish_runs(app, run_end.into_iter().collect()).await;
+
+    notify_changed(app).await;
+    Ok(())
+}
+
+/// 예상 시간 연장 (실행 중이면 남은 시간도 연장)
+pub async fn extend_task(app: &AppHandle, task_id: &str, minutes: i64) -> AppResult<()> {
+    ensure_writable(app)?;
+    let id = task_id.to_string();
+    write_db(app, move |conn| {
+        timer_store::extend_task(conn, &id, minutes, Some("트레이"))
+    })
+    .await?;
+
+    {
+        let timer_state = shared_timer_state(app);
+        let mut state = timer_state.lock().await;
+        if let Some(timer) = state.timers.get_mut(task_id) {
+            let remaining = timer.remaining_secs();
+            timer.set_remaining_secs(remaining + minutes.max(0) as u64 * 60);
+        }
+    }
+
+    notify_changed(app).await;
+    Ok(())
+}
+
+fn shared_timer_state(app: &AppHandle) -> SharedTimerState {
+    app.state::<SharedTimerState>().inner().clone()
+}
+
+/// 타이머 정지 (실행 기록은 타이머 잠금을 푼 뒤 저장)
+fn stop_timer(app: &AppHandle, state: &mut TimerState, task_id: &str) -> Option<TaskTimer> {
+    let stopped = state.stop(task_id)?;
+    emit_stopped(app, &stopped);
+    Some(stopped)
+}
+
+/// 저장할 남은 시간 (스톱워치는 남은 시간이 없으므로 None)
+fn remaining_of(timer: Option<&TaskTimer>) -> Option<i64> {
+    timer
+        .filter(|t| t.mode != TimerMode::Stopwatch)
+        .map(|t| t.remaining_secs() as i64)
+}
+
+/// 트레이/메인 화면 갱신
+async fn notify_changed(app: &AppHandle) {
+    let title = shared_timer_state(app).lock().await.tray_title();
+    update_tray(app, &title);
+    refresh_menu(app);
+    let _ = app.emit("tasks-changed", ());
+}
@@ -0,0 +1,258 @@
+use chrono::Utc;
+use tauri::{AppHandle, Manager};
+
+use super::cycle::TimerPhase;
+use super::{emit_stopped, persist, SharedTimerState, TimerMode, TrayDisplayMode};
+use crate::db::{ensure_writable, DbState};
+use crate::error::AppResult;
+use crate::settings::set_setting;
+use crate::tray::{update_tray, DEFAULT_TRAY_TITLE};
+
+// 트레이 타이머 시작 (Play 시 호출)
+// cycle이 true면 사이클 모드 (집중/휴식 구간을 엔진이 자동 전환, 첫 구간 길이는 설정값 사용)
+// cycle이 없으면 timer_cycle_enabled 설정을 따름
+#[tauri::command]
+pub async fn start_tray_timer(
+    state: tauri::State<'_, SharedTimerState>,
+    app: AppHandle,
+    task_id: String,
+    remaining_secs: u64,
+    task_title: String,
+    cycle: Option<bool>,
+) -> AppResult<()> {
+    log::info!(
+        "[Rust] start_tray_timer: task={}, title={}, secs={}, cycle={:?}",
+        task_id,
+        task_title,
+        remaining_secs,
+        cycle
+    );
+    // 읽기 전용 DB에는 실행 기록을 남길 수 없으므로 시작하지 않음
+    ensure_writable(&app)?;
+    // DB 작업 동안 타이머 잠금을 잡지 않도록 기존 타이머는 복사해서 확인
+    let existing = state.lock().await.timers.get(&task_id).cloned();
+
+    let (cycle, remaining_secs, mode) =
+        persist::resolve_countdown(&app, existing.as_ref(), cycle, remaining_secs).await;
+    let phase = cycle.as_ref().map(|c| c.phase).unwrap_or_default();
+
+    // 이미 실행 중이면 기존 실행 기록을 유지, 아니면 새 실행 기록 시작
+    let run = match existing.and_then(|t| t.run) {
+        Some(run) => Some(run),
+        None => persist::begin_run(&app, &task_id, phase, Utc::now()).await,
+    };
+
+    let mut timer = state.lock().await;
+    timer.start(&task_id, &task_title, remaining_secs, run, cycle, mode);
+
+    // 즉시 트레이 업데이트
+    update_tray(&app, &timer.tray_title());
+
+    Ok(())
+}
+
+// 스톱워치 시작 (예상 시간이 없는 task, 경과 시간을 세어 올림)
+// elapsed_secs: 이어서 측정할 경과 시간 (기본 0)
+#[tauri::command]
+pub async fn start_tray_stopwatch(
+    state: tauri::State<'_, SharedTimerState>,
+    app: AppHandle,
+    task_id: String,
+    task_title: String,
+    elapsed_secs: Option<u64>,
+) -> AppResult<()> {
+    log::info!(
+        "[Rust] start_tray_stopwatch: task={}, title={}, elapsed={:?}",
+        task_id,
+        task_title,
+        elapsed_secs
+    );
+    ensure_writable(&app)?;
+    let previous = {
+        let mut timer = state.lock().await;
+
+        // 이미 실행 중인 스톱워치는 그대로 유지
+        if let Some(existing) = timer.timers.get(&task_id) {
+            if existing.mode == TimerMode::Stopwatch {
+                timer.touch(&task_id);
+                update_tray(&app, &timer.tray_title());
+                return Ok(());
+            }
+        }
+
+        // 카운트다운 중이던 타이머는 실행 기록을 닫고 스톱워치로 전환
+        timer.stop(&task_id).map(|previous| {
+            emit_stopped(&app, &previous);
+            persist::RunEnd::finish(&previous, "paused")
+        })
+    };
+    persist::finish_runs(&app, previous.into_iter().collect()).await;
+    let run = persist::begin_run(&app, &task_id, TimerPhase::Focus, Utc::now()).await;
+
+    let mut timer = state.lock().await;
+    timer.start_stopwatch(&task_id, &task_title, elapsed_secs.unwrap_or(0), run);
+
+    update_tray(&app, &timer.tray_title());
+
+    Ok(())
+}
+
+// 트레이 타이머 정지 (Pause/Stop 시 호출)
+// task_id가 없으면 모든 타이머를 정지
+// 스톱워치도 같은 명령으로 정지하며 경과 시간은 실행 기록에 저장됨 (반환값은 0)
+#[tauri::command]
+pub async fn stop_tray_timer(
+    state: tauri::State<'_, SharedTimerState>,
+    app: AppHandle,
+    task_id: Option<String>,
+    update_to_slacker: Option<bool>,
+) -> AppResult<u64> {
+    log::info!(
+        "[Rust] stop_tray_timer: task={:?}, update_to_slacker={:?}",
+        task_id,
+        update_to_slacker
+    );
+    let mut timer = state.lock().await;
+
+    let mut run_ends = Vec::new();
+    let stopped = match task_id {
+        Some(id) => {
+            let stopped = timer.stop(&id);
+            if let Some(stopped) = &stopped {
+                run_ends.push(persist::RunEnd::finish(stopped, "paused"));
+                emit_stopped(&app, stopped);
+            }
+            stopped
+        }
+        None => {
+            let current = timer.tray_timer().cloned();
+            for (_, stopped) in timer.timers.drain() {
+                run_ends.push(persist::RunEnd::finish(&stopped, "paused"));
+                emit_stopped(&app, &stopped);
+            }
+            current
+        }
+    };
+    let remaining = stopped.as_ref().map(|t| t.remaining_secs()).unwrap_or(0);
+
+    if timer.tray_timer().is_some() {
+        // 다른 실행 중인 타이머가 있으면 그 타이머를 표시
+        update_tray(&app, &timer.tray_title());
+    } else if update_to_slacker.unwrap_or(false) {
+        // update_to_slacker가 true일 때만 "미루미"로 변경 (기본값은 false)
+        update_tray(&app, DEFAULT_TRAY_TITLE);
+    } else if let Some(stopped) = stopped {
+        // 일시정지 시 현재 태스크 제목과 남은 시간을 트레이에 고정하여 표시 (흐르지 않게 함)
+        update_tray(&app, &stopped.tray_title());
+    }
+    drop(timer);
+
+    // 실행 기록은 타이머 잠금을 푼 뒤 저장
+    persist::finish_runs(&app, run_ends).await;
+
+    Ok(remaining)
+}
+
+// 트레이 타이머 업데이트 (다른 실행 중인 task로 전환 시)
+// 이미 실행 중인 타이머는 남은 시간을 유지하고 트레이 표시 대상만 전환
+#[tauri::command]
+pub async fn update_tray_timer(
+    state: tauri::State<'_, SharedTimerState>,
+    app: AppHandle,
+    task_id: String,
+    remaining_secs: u64,
+    task_title: String,
+) -> AppResult<()> {
+    // 새 타이머는 실행 기록을 남기므로 읽기 전용 DB에서는 시작하지 않음
+    ensure_writable(&app)?;
+    {
+        let mut timer = state.lock().await;
+        if timer.touch(&task_id) {
+            update_tray(&app, &timer.tray_title());
+            return Ok(());
+        }
+    }
+
+    let run = persist::begin_run(&app, &task_id, TimerPhase::Focus, Utc::now()).await;
+    let mode = persist::load_timer_mode(&app).await;
+    let mut timer = state.lock().await;
+    timer.start(&task_id, &task_title, remaining_secs, run, None, mode);
+
+    // 즉시 트레이 업데이트
+    update_tray(&app, &timer.tray_title());
+
+    Ok(())
+}
+
+// 현재 남은 시간 조회 (포그라운드 복귀 시 호출)
+// task_id가 없으면 트레이에 표시 중인 타이머 기준
+// 초과 시간 측정 중이면 (0, true)
+// 남은 시간은 deadline 기준으로 계산되므로 절전 복귀 직후에도 실제 시간과 일치
+#[tauri::command]
+pub async fn get_remaining_time(
+    state: tauri::State<'_, SharedTimerState>,
+    task_id: Option<String>,
+) -> AppResult<(u64, bool)> {
+    let timer = state.lock().await;
+    let current = match task_id {
+        Some(id) => timer.timers.get(&id),
+        None => timer.tray_timer(),
+    };
+    Ok(current
+        .map(|t| (t.remaining_secs(), t.is_running()))
+        .unwrap_or((0, false)))
+}
+
+// 스톱워치 경과 시간 조회
+// task_id가 없으면 트레이에 표시 중인 타이머 기준
+#[tauri::command]
+pub async fn get_elapsed_time(
+    state: tauri::State<'_, SharedTimerState>,
+    task_id: Option<String>,
+) -> AppResult<(u64, bool)> {
+    let timer = state.lock().await;
+    let current = match task_id {
+        Some(id) => timer.timers.get(&id),
+        None => timer.tray_timer(),
+    };
+    Ok(current
+        .filter(|t| t.mode == TimerMode::Stopwatch)
+        .map(|t| (t.elapsed_secs(), true))
+        .unwrap_or((0, false)))
+}
+
+// 트레이 타이머 시간 동기화 (앱에서 시간 변경 시)
+#[tauri::command]
+pub async fn sync_tray_timer(
+    state: tauri::State<'_, SharedTimerState>,
+    app: AppHandle,
+    task_id: Option<String>,
+    remaining_secs: u64,
+) -> AppResult<()> {
+    let mut timer = state.lock().await;
+    let target = match task_id {
+        Some(id) => Some(id),
+        None => timer.tray_timer().map(|t| t.task_id.clone()),
+    };
+
+    if let Some(current) = target.and_then(|id| timer.timers.get_mut(&id)) {
+        current.set_remaining_secs(remaining_secs);
+        update_tray(&app, &timer.tray_title());
+    }
+
+    Ok(())
+}
+
+// 트레이 표시 방식 변경 (최근 시작 / 가장 먼저 종료)
+// tray_display_mode 설정으로 저장되어 다음 실행에도 유지되고 setting-changed로 전파됨
+#[tauri::command]
+pub async fn set_tray_display_mode(app: AppHandle, mode: TrayDisplayMode) -> AppResult<()> {
+    let value = serde_json::to_value(mode)?;
+    set_setting(
+        app.clone(),
+        app.state::<DbState>(),
+        "tray_display_mode".to_string(),
+        value,
+    )
+    .await
+}
@@ -0,0 +1,171 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+
+/// 타이머 단계 (일반 타이머는 항상 Focus)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerPhase {
+    #[default]
+    Focus,
+    ShortBreak,
+    LongBreak,
+}
+
+impl TimerPhase {
+    /// tbl_task_run_history.phase 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Focus => "focus",
+            Self::ShortBreak => "short_break",
+            Self::LongBreak => "long_break",
+        }
+    }
+
+    pub fn is_break(&self) -> bool {
+        !matches!(self, Self::Focus)
+    }
+}
+
+/// 사이클 모드 설정 (tbl_setting의 pomodoro_* 키)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleConfig {
+    pub focus_secs: u64,
+    pub short_break_secs: u64,
+    pub long_break_secs: u64,
+    /// 사이클당 집중 구간 수
+    pub intervals: u32,
+}
+
+impl Default for CycleConfig {
+    fn default() -> Self {
+        Self {
+            focus_secs: 25 * 60,
+            short_break_secs: 5 * 60,
+            long_break_secs: 15 * 60,
+            intervals: 4,
+        }
+    }
+}
+
+impl CycleConfig {
+    /// 설정값 로드 (없거나 잘못된 값은 기본값 사용, 범위는 settings::REGISTRY에서 검증)
+    pub fn load(conn: &Connection) -> Self {
+        let minutes = |key: &str| settings::get_int(conn, key).max(0) as u64 * 60;
+
+        Self {
+            focus_secs: minutes("pomodoro_focus_minutes").max(60),
+            short_break_secs: minutes("pomodoro_short_break_minutes"),
+            long_break_secs: minutes("pomodoro_long_break_minutes"),
+            intervals: settings::get_int(conn, "pomodoro_intervals").max(1) as u32,
+        }
+    }
+}
+
+/// 사이클 모드 진행 상태
+///
+/// 집중 → 짧은 휴식 → … → 마지막 집중 → 긴 휴식 순서로 진행되며,
+/// 길이가 0인 휴식 단계는 건너뛴다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerCycle {
+    pub config: CycleConfig,
+    pub phase: TimerPhase,
+    /// 완료한 집중 구간 수
+    pub focus_completed: u32,
+}
+
+impl TimerCycle {
+    pub fn new(config: CycleConfig) -> Self {
+        Self {
+            config,
+            phase: TimerPhase::Focus,
+            focus_completed: 0,
+        }
+    }
+
+    /// 현재 단계를 마치고 다음 단계로 전환, 다음 단계 길이(초)를 반환
+    /// 사이클이 끝났으면 None
+    pub fn advance(&mut self) -> Option<u64> {
+        match self.phase {
+            TimerPhase::Focus => {
+                self.focus_completed += 1;
+                if self.focus_completed >= self.config.intervals {
+                    self.enter(TimerPhase::LongBreak, self.config.long_break_secs)
+                } else if self.config.short_break_secs > 0 {
+                    self.enter(TimerPhase::ShortBreak, self.config.short_break_secs)
+                } else {
+                    self.enter(TimerPhase::Focus, self.config.focus_secs)
+                }
+            }
+            TimerPhase::ShortBreak => self.enter(TimerPhase::Focus, self.config.focus_secs),
+            TimerPhase::LongBreak => None,
+        }
+    }
+
+    fn enter(&mut self, phase: TimerPhase, secs: u64) -> Option<u64> {
+        if secs == 0 {
+            return None;
+        }
+        self.phase = phase;
+        Some(secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(intervals: u32, short_break_secs: u64, long_break_secs: u64) -> CycleConfig {
+        CycleConfig {
+            focus_secs: 25 * 60,
+            short_break_secs,
+            long_break_secs,
+            intervals,
+        }
+    }
+
+    #[test]
+    fn focus_and_short_break_alternate() {
+        let mut cycle = TimerCycle::new(config(3, 5 * 60, 15 * 60));
+
+        assert_eq!(cycle.advance(), Some(5 * 60));
+        assert_eq!(cycle.phase, TimerPhase::ShortBreak);
+        assert_eq!(cycle.focus_completed, 1);
+
+        assert_eq!(cycle.advance(), Some(25 * 60));
+        assert_eq!(cycle.phase, TimerPhase::Focus);
+        assert_eq!(cycle.focus_completed, 1);
+    }
+
+    #[test]
+    fn long_break_follows_last_interval_and_ends_cycle() {
+        let mut cycle = TimerCycle::new(config(2, 5 * 60, 15 * 60));
+
+        cycle.advance(); // 집중 1 → 짧은 휴식
+        cycle.advance(); // 짧은 휴식 → 집중 2
+        assert_eq!(cycle.advance(), Some(15 * 60));
+        assert_eq!(cycle.phase, TimerPhase::LongBreak);
+        assert_eq!(cycle.focus_completed, 2);
+
+        assert_eq!(cycle.advance(), None);
+        assert_eq!(cycle.phase, TimerPhase::LongBreak);
+    }
+
+    #[test]
+    fn zero_length_breaks_are_skipped() {
+        let mut cycle = TimerCycle::new(config(2, 0, 0));
+
+        // 짧은 휴식이 0이면 바로 다음 집중 구간
+        assert_eq!(cycle.advance(), Some(25 * 60));
+        assert_eq!(cycle.phase, TimerPhase::Focus);
+        assert_eq!(cycle.focus_completed, 1);
+
+        // 긴 휴식이 0이면 마지막 집중 구간에서 사이클 종료
+        assert_eq!(cycle.advance(), None);
+        assert_eq!(cycle.phase, TimerPhase::Focus);
+        assert_eq!(cycle.focus_completed, 2);
+    }
+}
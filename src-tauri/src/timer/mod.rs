@@ -0,0 +1,818 @@
+pub mod actions;
+pub mod commands;
+pub mod cycle;
+pub mod notify;
+pub mod persist;
+
+pub use commands::*;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Mutex;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::db::models::TimerCheckpoint;
+use crate::settings;
+use crate::tray::{
+    format_tray_overtime_title, format_tray_title, refresh_menu, update_tray, update_tray_icon,
+    TrayIconStyle, TrayProgress, DEFAULT_TRAY_TITLE,
+};
+use cycle::{TimerCycle, TimerPhase};
+use notify::NotifyConfig;
+use persist::{parse_db_datetime, TimerRun, CHECKPOINT_INTERVAL_SECS};
+
+/// 여러 타이머가 동시에 실행 중일 때 트레이에 표시할 타이머 선택 방식
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrayDisplayMode {
+    /// 가장 최근에 시작된 타이머
+    #[default]
+    Recent,
+    /// 가장 먼저 종료될 타이머
+    Soonest,
+}
+
+/// 타이머 모드
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerMode {
+    /// 남은 시간이 0이 되면 종료
+    #[default]
+    Countdown,
+    /// 남은 시간이 0이 된 뒤에도 초과 시간을 계속 측정 (정지 시 실행 기록에 저장)
+    Overtime,
+    /// 예상 시간 없이 경과 시간만 측정
+    Stopwatch,
+}
+
+impl TimerMode {
+    /// tbl_timer_checkpoint.mode 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Countdown => "countdown",
+            Self::Overtime => "overtime",
+            Self::Stopwatch => "stopwatch",
+        }
+    }
+}
+
+impl From<&str> for TimerMode {
+    fn from(s: &str) -> Self {
+        match s {
+            "overtime" => Self::Overtime,
+            "stopwatch" => Self::Stopwatch,
+            _ => Self::Countdown,
+        }
+    }
+}
+
+/// Task별 타이머
+///
+/// 남은 시간을 매 tick마다 감소시키지 않고 종료 시각(deadline)으로부터 계산한다.
+/// 단조 시계(`Instant`)는 시스템 시계 변경에 영향을 받지 않지만 macOS/Linux에서
+/// 절전 중에는 멈추므로, 벽시계 기준 deadline과 함께 보관하여 더 작은 값을 사용한다.
+/// 스톱워치는 시작 시점을 deadline으로 두고 그 이후 경과 시간을 센다.
+#[derive(Debug, Clone)]
+pub struct TaskTimer {
+    pub task_id: String,
+    pub task_title: String,
+    /// 시작 순번 (값이 클수록 최근에 시작됨)
+    pub started_seq: u64,
+    /// 벽시계 기준 종료 시각
+    deadline: SystemTime,
+    /// 단조 시계 기준점
+    anchor: Instant,
+    /// 기준점 시점의 남은 시간
+    anchor_remaining: Duration,
+    /// 현재 구간 전체 길이 (트레이 진행 아이콘용)
+    total: Duration,
+    /// 연결된 실행 기록 (DB 미설정 시 None)
+    pub run: Option<TimerRun>,
+    /// 사이클 모드 진행 상태 (일반 타이머는 None)
+    pub cycle: Option<TimerCycle>,
+    pub mode: TimerMode,
+    /// 이전 구간에서 이어받은 경과 시간 (초과 시간: 연장 전 초과분, 스톱워치: 시작 시 경과 시간)
+    carried: Duration,
+    /// 초과 시간 진입 알림 여부
+    overtime_notified: bool,
+    /// 이전 tick의 남은 시간 (종료 전 알림 시점 통과 판정용)
+    last_tick_secs: Option<u64>,
+}
+
+impl TaskTimer {
+    fn new(
+        task_id: &str,
+        task_title: &str,
+        remaining_secs: u64,
+        started_seq: u64,
+        run: Option<TimerRun>,
+        cycle: Option<TimerCycle>,
+        mode: TimerMode,
+    ) -> Self {
+        let remaining = Duration::from_secs(remaining_secs);
+        Self {
+            task_id: task_id.to_string(),
+            task_title: task_title.to_string(),
+            started_seq,
+            deadline: SystemTime::now() + remaining,
+            anchor: Instant::now(),
+            anchor_remaining: remaining,
+            total: remaining,
+            run,
+            cycle,
+            mode,
+            carried: Duration::ZERO,
+            overtime_notified: false,
+            last_tick_secs: None,
+        }
+    }
+
+    /// 스톱워치 생성 (elapsed_secs: 이어서 측정할 경과 시간)
+    fn stopwatch(
+        task_id: &str,
+        task_title: &str,
+        elapsed_secs: u64,
+        started_seq: u64,
+        run: Option<TimerRun>,
+    ) -> Self {
+        let mut timer = Self::new(
+            task_id,
+            task_title,
+            0,
+            started_seq,
+            run,
+            None,
+            TimerMode::Stopwatch,
+        );
+        timer.carried = Duration::from_secs(elapsed_secs);
+        timer
+    }
+
+    /// 체크포인트에서 복구 (앱이 꺼져 있던 동안에도 deadline 기준으로 시간이 흐른 것으로 간주)
+    fn restore(checkpoint: &TimerCheckpoint, started_seq: u64) -> Option<Self> {
+        let deadline = SystemTime::from(parse_db_datetime(&checkpoint.deadline_at)?);
+        let remaining = deadline
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO);
+        let run = match (&checkpoint.run_id, &checkpoint.run_started_at) {
+            (Some(id), Some(started_at)) => Some(TimerRun {
+                id: id.clone(),
+                started_at: parse_db_datetime(started_at)?,
+            }),
+            _ => None,
+        };
+        let cycle: Option<TimerCycle> = checkpoint
+            .cycle
+            .as_deref()
+            .and_then(|c| serde_json::from_str(c).ok());
+
+        // 체크포인트의 측정 시간 중 deadline 이후 경과분은 다시 계산되므로 이전 구간 몫만 보관
+        let mode = TimerMode::from(checkpoint.mode.as_str());
+        let counted = match mode {
+            TimerMode::Stopwatch => checkpoint.elapsed_seconds,
+            _ => checkpoint.overtime_seconds,
+        };
+        let counted_at_checkpoint = parse_db_datetime(&checkpoint.checkpointed_at)
+            .map(|at| (at - DateTime::<Utc>::from(deadline)).num_seconds().max(0))
+            .unwrap_or(0);
+        let carried = (counted - counted_at_checkpoint).max(0) as u64;
+
+        // 구간 길이는 저장하지 않으므로 사이클 단계 길이 또는 실행 기록 시작 시각 기준으로 계산
+        let phase_secs = cycle.as_ref().map(|c| match c.phase {
+            TimerPhase::Focus => c.config.focus_secs,
+            TimerPhase::ShortBreak => c.config.short_break_secs,
+            TimerPhase::LongBreak => c.config.long_break_secs,
+        });
+        let total = match (phase_secs, &run) {
+            (Some(secs), _) => Duration::from_secs(secs),
+            (None, Some(run)) => deadline
+                .duration_since(SystemTime::from(run.started_at))
+                .unwrap_or(remaining),
+            (None, None) => remaining,
+        };
+
+        Some(Self {
+            task_id: checkpoint.task_id.clone(),
+            task_title: checkpoint.task_title.clone(),
+            started_seq,
+            deadline,
+            anchor: Instant::now(),
+            anchor_remaining: remaining,
+            total: total.max(remaining),
+            run,
+            cycle,
+            mode,
+            carried: Duration::from_secs(carried),
+            overtime_notified: false,
+            last_tick_secs: None,
+        })
+    }
+
+    /// 남은 시간 (단조 시계/벽시계 중 더 많이 경과한 쪽 기준)
+    pub fn remaining(&self) -> Duration {
+        let monotonic = self.anchor_remaining.saturating_sub(self.anchor.elapsed());
+        let wall = self
+            .deadline
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO);
+        monotonic.min(wall)
+    }
+
+    /// 남은 시간 (초, 올림)
+    pub fn remaining_secs(&self) -> u64 {
+        let remaining = self.remaining();
+        remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+    }
+
+    /// 남은 시간 재설정 (시간 연장/동기화 시, 스톱워치는 무시)
+    pub fn set_remaining_secs(&mut self, remaining_secs: u64) {
+        if self.mode == TimerMode::Stopwatch {
+            return;
+        }
+        // 연장 전까지의 초과 시간은 유지
+        self.carried = self.overtime();
+        self.overtime_notified = false;
+        self.last_tick_secs = None;
+        let remaining = Duration::from_secs(remaining_secs);
+        // 이미 진행한 시간은 유지하고 구간 길이만 조정
+        self.total = self.total.saturating_sub(self.remaining()) + remaining;
+        self.deadline = SystemTime::now() + remaining;
+        self.anchor = Instant::now();
+        self.anchor_remaining = remaining;
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// 실행 중 여부 (초과 시간/스톱워치 모드는 0이 된 뒤에도 실행 중)
+    pub fn is_running(&self) -> bool {
+        self.mode != TimerMode::Countdown || !self.is_expired()
+    }
+
+    /// 남은 시간이 0이 되면 종료 처리할 타이머인지 여부
+    fn is_counting_down(&self) -> bool {
+        self.mode != TimerMode::Stopwatch
+    }
+
+    /// 초과 시간 측정 중 여부
+    pub fn in_overtime(&self) -> bool {
+        self.mode == TimerMode::Overtime && self.is_expired()
+    }
+
+    /// deadline 이후 경과 시간 + 이전 구간에서 이어받은 시간
+    fn counted_up(&self) -> Duration {
+        let monotonic = self.anchor.elapsed().saturating_sub(self.anchor_remaining);
+        let wall = SystemTime::now()
+            .duration_since(self.deadline)
+            .unwrap_or(Duration::ZERO);
+        self.carried + monotonic.max(wall)
+    }
+
+    /// 초과 시간 (초과 시간 모드가 아니면 0)
+    pub fn overtime(&self) -> Duration {
+        if self.mode != TimerMode::Overtime {
+            return Duration::ZERO;
+        }
+        self.counted_up()
+    }
+
+    /// 초과 시간 (초)
+    pub fn overtime_secs(&self) -> u64 {
+        self.overtime().as_secs()
+    }
+
+    /// 스톱워치 경과 시간 (초, 스톱워치가 아니면 0)
+    pub fn elapsed_secs(&self) -> u64 {
+        if self.mode != TimerMode::Stopwatch {
+            return 0;
+        }
+        self.counted_up().as_secs()
+    }
+
+    /// 벽시계 기준 종료 시각 (이미 만료된 경우 deadline 시점)
+    pub fn deadline_at(&self) -> DateTime<Utc> {
+        if self.is_expired() {
+            return DateTime::<Utc>::from(self.deadline.min(SystemTime::now()));
+        }
+        DateTime::<Utc>::from(SystemTime::now() + self.remaining())
+    }
+
+    /// 실행 기록 종료 시각 (카운트다운이 이미 만료된 경우 deadline 시점)
+    pub fn run_ended_at(&self) -> DateTime<Utc> {
+        if self.mode != TimerMode::Countdown {
+            return Utc::now();
+        }
+        Utc::now().min(DateTime::<Utc>::from(self.deadline))
+    }
+
+    /// 현재 단계
+    pub fn phase(&self) -> TimerPhase {
+        self.cycle.as_ref().map(|c| c.phase).unwrap_or_default()
+    }
+
+    /// 트레이 표시용 이름 (휴식 단계는 "휴식")
+    pub fn tray_label(&self) -> &str {
+        if self.phase().is_break() {
+            "휴식"
+        } else {
+            &self.task_title
+        }
+    }
+
+    /// 트레이 타이틀 (초과 시간 측정 중이면 +MM:SS, 스톱워치는 경과 시간)
+    pub fn tray_title(&self) -> String {
+        if self.mode == TimerMode::Stopwatch {
+            format_tray_title(self.tray_label(), self.elapsed_secs())
+        } else if self.in_overtime() {
+            format_tray_overtime_title(self.tray_label(), self.overtime_secs())
+        } else {
+            format_tray_title(self.tray_label(), self.remaining_secs())
+        }
+    }
+
+    /// 트레이 진행 아이콘 상태
+    /// (카운트다운: 현재 구간 진행률, 초과 시간: 가득 찬 링, 스톱워치: 1시간 단위로 채움)
+    pub fn tray_progress(&self) -> TrayProgress {
+        let (fraction, style) = if self.mode == TimerMode::Stopwatch {
+            let secs = self.elapsed_secs() % 3600;
+            (secs as f32 / 3600.0, TrayIconStyle::Stopwatch)
+        } else if self.in_overtime() {
+            (1.0, TrayIconStyle::Overtime)
+        } else {
+            let total = self.total.as_secs_f32();
+            let fraction = if total > 0.0 {
+                1.0 - self.remaining().as_secs_f32() / total
+            } else {
+                1.0
+            };
+            let style = if self.phase().is_break() {
+                TrayIconStyle::Break
+            } else {
+                TrayIconStyle::Focus
+            };
+            (fraction, style)
+        };
+        TrayProgress { fraction, style }
+    }
+
+    /// 다음 단계 시작 (이전 deadline에 이어서 계산하여 절전 중 지나간 단계도 순서대로 처리)
+    /// 새 단계의 시작 시각을 반환
+    fn chain_phase(&mut self, phase_secs: u64) -> DateTime<Utc> {
+        let phase_started = self.deadline;
+        self.last_tick_secs = None;
+        self.deadline = phase_started + Duration::from_secs(phase_secs);
+        self.total = Duration::from_secs(phase_secs);
+        self.anchor = Instant::now();
+        self.anchor_remaining = self
+            .deadline
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO);
+        DateTime::<Utc>::from(phase_started)
+    }
+
+    /// timer-tick 이벤트 페이로드
+    pub fn tick_payload(&self, running: bool) -> TimerTickPayload {
+        TimerTickPayload {
+            task_id: self.task_id.clone(),
+            mode: self.mode,
+            phase: self.phase(),
+            remaining_secs: self.remaining_secs(),
+            elapsed_secs: self.elapsed_secs(),
+            overtime_secs: self.overtime_secs(),
+            running: running && self.is_running(),
+        }
+    }
+
+    /// 실행 기록 경과 시간 (초)
+    pub fn run_duration_secs(&self) -> i64 {
+        self.run
+            .as_ref()
+            .map(|run| (self.run_ended_at() - run.started_at).num_seconds().max(0))
+            .unwrap_or(0)
+    }
+}
+
+/// 타이머 종료 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerEndedPayload {
+    pub task_id: String,
+}
+
+/// 매초 전송하는 타이머 상태 이벤트 페이로드 (모든 창이 같은 시계를 표시하도록)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerTickPayload {
+    pub task_id: String,
+    pub mode: TimerMode,
+    pub phase: TimerPhase,
+    /// 남은 시간 (초, 스톱워치는 0)
+    pub remaining_secs: u64,
+    /// 스톱워치 경과 시간 (초)
+    pub elapsed_secs: u64,
+    /// 초과 시간 (초)
+    pub overtime_secs: u64,
+    /// false면 정지된 타이머의 마지막 상태
+    pub running: bool,
+}
+
+/// 사이클 단계 전환 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerPhaseChangedPayload {
+    pub task_id: String,
+    pub phase: TimerPhase,
+    pub focus_completed: u32,
+    pub intervals: u32,
+    pub remaining_secs: u64,
+}
+
+// 타이머 상태 관리 (task id → 실행 중인 타이머)
+#[derive(Default)]
+pub struct TimerState {
+    pub timers: HashMap<String, TaskTimer>,
+    pub display_mode: TrayDisplayMode,
+    /// 알림 설정 (타이머 루프에서 주기적으로, 설정 변경 시 즉시 갱신)
+    pub notify: NotifyConfig,
+    next_seq: u64,
+}
+
+pub type SharedTimerState = Arc<Mutex<TimerState>>;
+
+impl TimerState {
+    /// 타이머 시작 (이미 있으면 남은 시간을 덮어쓰고 최근 시작으로 갱신)
+    pub fn start(
+        &mut self,
+        task_id: &str,
+        task_title: &str,
+        remaining_secs: u64,
+        run: Option<TimerRun>,
+        cycle: Option<TimerCycle>,
+        mode: TimerMode,
+    ) {
+        let started_seq = self.bump_seq();
+        self.timers.insert(
+            task_id.to_string(),
+            TaskTimer::new(
+                task_id,
+                task_title,
+                remaining_secs,
+                started_seq,
+                run,
+                cycle,
+                mode,
+            ),
+        );
+    }
+
+    /// 스톱워치 시작 (이미 있으면 교체하고 최근 시작으로 갱신)
+    pub fn start_stopwatch(
+        &mut self,
+        task_id: &str,
+        task_title: &str,
+        elapsed_secs: u64,
+        run: Option<TimerRun>,
+    ) {
+        let started_seq = self.bump_seq();
+        self.timers.insert(
+            task_id.to_string(),
+            TaskTimer::stopwatch(task_id, task_title, elapsed_secs, started_seq, run),
+        );
+    }
+
+    /// 타이머를 가장 최근 시작된 것으로 표시 (트레이 표시 대상 전환용)
+    pub fn touch(&mut self, task_id: &str) -> bool {
+        let started_seq = self.bump_seq();
+        match self.timers.get_mut(task_id) {
+            Some(timer) => {
+                timer.started_seq = started_seq;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 타이머 정지 후 제거
+    pub fn stop(&mut self, task_id: &str) -> Option<TaskTimer> {
+        self.timers.remove(task_id)
+    }
+
+    /// 트레이에 표시할 타이머
+    pub fn tray_timer(&self) -> Option<&TaskTimer> {
+        match self.display_mode {
+            TrayDisplayMode::Recent => self.timers.values().max_by_key(|t| t.started_seq),
+            TrayDisplayMode::Soonest => self
+                .timers
+                .values()
+                // 종료 시각이 없는 스톱워치는 후순위
+                .min_by_key(|t| {
+                    (
+                        !t.is_counting_down(),
+                        t.remaining(),
+                        std::cmp::Reverse(t.started_seq),
+                    )
+                }),
+        }
+    }
+
+    /// 현재 상태 기준 트레이 진행 아이콘 (실행 중인 타이머가 없으면 None)
+    pub fn tray_progress(&self) -> Option<TrayProgress> {
+        self.tray_timer().map(|t| t.tray_progress())
+    }
+
+    /// 현재 상태 기준 트레이 타이틀
+    pub fn tray_title(&self) -> String {
+        match self.tray_timer() {
+            Some(timer) => timer.tray_title(),
+            None => DEFAULT_TRAY_TITLE.to_string(),
+        }
+    }
+
+    fn bump_seq(&mut self) -> u64 {
+        self.next_seq += 1;
+        self.next_seq
+    }
+}
+
+/// DB 복구 시 이어서 실행할 타이머 등록
+pub fn restore_timers(app: &AppHandle, checkpoints: Vec<TimerCheckpoint>) {
+    if checkpoints.is_empty() {
+        return;
+    }
+
+    let app = app.clone();
+    let timer_state = app.state::<SharedTimerState>().inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let mut state = timer_state.lock().await;
+        for checkpoint in &checkpoints {
+            let started_seq = state.bump_seq();
+            match TaskTimer::restore(checkpoint, started_seq) {
+                Some(timer) => {
+                    log::info!(
+                        "[Rust] Timer restored: {} ({})",
+                        timer.task_title,
+                        timer.task_id
+                    );
+                    state.timers.insert(timer.task_id.clone(), timer);
+                }
+                None => log::warn!("[Rust] Invalid checkpoint for {}", checkpoint.task_id),
+            }
+        }
+        update_tray(&app, &state.tray_title());
+    });
+}
+
+/// 실행 중인 타이머를 기록 없이 모두 제거 (DB 복원 등 데이터가 교체될 때)
+pub async fn clear_timers(app: &AppHandle) {
+    let timer_state = app.state::<SharedTimerState>().inner().clone();
+    let mut state = timer_state.lock().await;
+    drain_timers(app, &mut state);
+}
+
+/// DB 전환 전 실행 중인 타이머를 현재 DB에 저장하고 정리 (해당 DB를 다시 열면 복구됨)
+pub async fn suspend_timers(app: &AppHandle) {
+    let timer_state = app.state::<SharedTimerState>().inner().clone();
+    let checkpoints = {
+        let mut state = timer_state.lock().await;
+        let checkpoints = persist::checkpoints(state.timers.values());
+        drain_timers(app, &mut state);
+        checkpoints
+    };
+    persist::save_checkpoints(app, checkpoints).await;
+}
+
+fn drain_timers(app: &AppHandle, state: &mut TimerState) {
+    for (_, timer) in state.timers.drain() {
+        emit_stopped(app, &timer);
+    }
+    update_tray(app, &state.tray_title());
+    update_tray_icon(app, None);
+}
+
+/// 설정 변경 반영 (`set_setting` 후 호출)
+///
+/// 알림 설정은 다음 tick부터, 사이클 설정은 실행 중인 사이클의 다음 단계부터 적용된다.
+/// 초과 시간 설정은 새로 시작하는 타이머에만 적용된다.
+pub async fn apply_setting(app: &AppHandle, key: &str, value: &Value) {
+    let timer_state = app.state::<SharedTimerState>().inner().clone();
+    match key {
+        "timer_warning_minutes" | "notification_sound" => {
+            let notify = persist::load_notify_config(app).await;
+            timer_state.lock().await.notify = notify;
+        }
+        "pomodoro_focus_minutes"
+        | "pomodoro_short_break_minutes"
+        | "pomodoro_long_break_minutes"
+        | "pomodoro_intervals" => {
+            let config = persist::load_cycle_config(app).await;
+            let mut state = timer_state.lock().await;
+            for cycle in state.timers.values_mut().filter_map(|t| t.cycle.as_mut()) {
+                cycle.config = config.clone();
+            }
+        }
+        "tray_display_mode" => {
+            let mut state = timer_state.lock().await;
+            state.display_mode = serde_json::from_value(value.clone()).unwrap_or_default();
+            update_tray(app, &state.tray_title());
+            update_tray_icon(app, state.tray_progress());
+        }
+        _ => {}
+    }
+}
+
+/// 정지된 타이머의 마지막 상태 전송 (창이 다음 tick을 기다리지 않고 멈추도록)
+pub fn emit_stopped(app: &AppHandle, timer: &TaskTimer) {
+    let _ = app.emit("timer-tick", timer.tick_payload(false));
+}
+
+/// 실행 중인 모든 타이머 체크포인트 저장 (앱 종료 시 호출)
+pub async fn checkpoint_all(app: &AppHandle, timer_state: &SharedTimerState) {
+    let checkpoints = persist::checkpoints(timer_state.lock().await.timers.values());
+    persist::save_checkpoints(app, checkpoints).await;
+}
+
+/// 사이클 단계 전환으로 새로 시작할 실행 기록
+struct PhaseRun {
+    task_id: String,
+    phase: TimerPhase,
+    started_at: DateTime<Utc>,
+}
+
+/// 백그라운드 타이머 루프 (1초마다)
+///
+/// tick은 화면 갱신과 종료 판정 용도일 뿐이며 남은 시간은 deadline에서 계산한다.
+/// 절전 복귀 후 첫 tick에서 이미 지난 deadline을 감지해 `timer-ended`를 보낸다.
+/// 초과 시간 모드는 정지하지 않고 `timer-overtime`을 보낸 뒤 계속 측정한다.
+/// 창이 숨겨져 있어도 알림이 가도록 종료 전/종료 알림도 여기서 보낸다.
+///
+/// DB 작업(실행 기록, 체크포인트, 설정 로드)은 저장할 값을 잠금 안에서 계산한 뒤
+/// 타이머 잠금을 풀고 실행하여 tick과 타이머 커맨드가 DB를 기다리지 않도록 한다.
+pub async fn run_timer_loop(app_handle: AppHandle, timer_state: SharedTimerState) {
+    let mut ticker = interval(Duration::from_secs(1));
+    // 절전 등으로 밀린 tick을 몰아서 실행하지 않음
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    log::info!("[Rust] Timer loop started");
+    let mut last_checkpoint = Instant::now();
+    {
+        let notify = persist::load_notify_config(&app_handle).await;
+        let mut state = timer_state.lock().await;
+        state.notify = notify;
+        state.display_mode =
+            serde_json::from_value(settings::machine_setting(&app_handle, "tray_display_mode"))
+                .unwrap_or_default();
+    }
+
+    loop {
+        ticker.tick().await;
+
+        let mut run_ends = Vec::new();
+        let mut phase_runs = Vec::new();
+        let mut checkpoints = None;
+        {
+            let mut state = timer_state.lock().await;
+            if state.timers.is_empty() {
+                continue;
+            }
+            let notify_config = state.notify.clone();
+
+            // 종료 전 알림 (이전 tick 이후 알림 시점을 지나간 경우)
+            for timer in state.timers.values_mut() {
+                if !timer.is_counting_down() || timer.is_expired() {
+                    continue;
+                }
+                let remaining = timer.remaining_secs();
+                let crossed = timer
+                    .last_tick_secs
+                    .replace(remaining)
+                    .and_then(|previous| notify_config.crossed_warning(previous, remaining));
+                if let Some(secs) = crossed {
+                    notify::send_warning(&app_handle, &notify_config, timer, secs);
+                }
+            }
+
+            // deadline이 지난 타이머 수집 (초과 시간 측정 중인 타이머, 스톱워치 제외)
+            let ended: Vec<String> = state
+                .timers
+                .values()
+                .filter(|t| t.is_counting_down() && t.is_expired() && !t.overtime_notified)
+                .map(|t| t.task_id.clone())
+                .collect();
+
+            // 타이머 종료 시
+            for task_id in ended {
+                // 사이클 모드면 다음 단계로 전환 (새 실행 기록은 잠금을 푼 뒤 시작)
+                if let Some(timer) = state.timers.get_mut(&task_id) {
+                    if let Some(phase_secs) = timer.cycle.as_mut().and_then(TimerCycle::advance) {
+                        run_ends.push(persist::RunEnd::finish(timer, "completed"));
+                        let phase_started = timer.chain_phase(phase_secs);
+                        timer.run = None;
+                        phase_runs.push(PhaseRun {
+                            task_id: timer.task_id.clone(),
+                            phase: timer.phase(),
+                            started_at: phase_started,
+                        });
+
+                        let cycle = timer.cycle.as_ref().expect("cycle timer");
+                        log::info!(
+                            "[Rust] Timer phase changed: {} → {:?}",
+                            timer.task_id,
+                            cycle.phase
+                        );
+                        let _ = app_handle.emit(
+                            "timer-phase-changed",
+                            TimerPhaseChangedPayload {
+                                task_id: timer.task_id.clone(),
+                                phase: cycle.phase,
+                                focus_completed: cycle.focus_completed,
+                                intervals: cycle.config.intervals,
+                                remaining_secs: timer.remaining_secs(),
+                            },
+                        );
+                        notify::send_phase_changed(&app_handle, &notify_config, timer);
+                        continue;
+                    }
+
+                    // 초과 시간 모드면 정지하지 않고 계속 측정
+                    if timer.mode == TimerMode::Overtime {
+                        timer.overtime_notified = true;
+                        log::info!("[Rust] Timer overtime started: {}", timer.task_id);
+                        notify::send_ended(&app_handle, &notify_config, timer);
+                        let _ = app_handle.emit("timer-overtime", TimerEndedPayload { task_id });
+                        continue;
+                    }
+                }
+
+                if let Some(timer) = state.stop(&task_id) {
+                    log::info!(
+                        "[Rust] Timer ended: {} ({})",
+                        timer.task_title,
+                        timer.task_id
+                    );
+                    run_ends.push(persist::RunEnd::expire(&timer));
+                    emit_stopped(&app_handle, &timer);
+                    notify::send_ended(&app_handle, &notify_config, &timer);
+                    let _ = app_handle.emit("timer-ended", TimerEndedPayload { task_id });
+                }
+            }
+
+            // 주기적으로 실행 중인 타이머 저장 (앱 재시작 시 복구용)
+            if last_checkpoint.elapsed() >= Duration::from_secs(CHECKPOINT_INTERVAL_SECS) {
+                checkpoints = Some(persist::checkpoints(state.timers.values()));
+                last_checkpoint = Instant::now();
+            }
+
+            // 실행 중인 타이머 상태 전송
+            for timer in state.timers.values() {
+                let _ = app_handle.emit("timer-tick", timer.tick_payload(true));
+            }
+
+            // 트레이 업데이트 (타이틀을 표시하지 않는 패널을 위해 진행 아이콘도 함께 갱신)
+            update_tray(&app_handle, &state.tray_title());
+            update_tray_icon(&app_handle, state.tray_progress());
+        }
+
+        // 이하 DB 작업은 타이머 잠금 없이 실행
+        let expired = run_ends.iter().any(|end| end.is_expire());
+        persist::finish_runs(&app_handle, run_ends).await;
+        if expired {
+            refresh_menu(&app_handle);
+        }
+
+        for phase_run in phase_runs {
+            let run = persist::begin_run(
+                &app_handle,
+                &phase_run.task_id,
+                phase_run.phase,
+                phase_run.started_at,
+            )
+            .await;
+            // 그 사이 정지되었거나 다른 단계로 넘어간 타이머면 실행 기록을 바로 닫음
+            let mut state = timer_state.lock().await;
+            match state.timers.get_mut(&phase_run.task_id) {
+                Some(timer) if timer.run.is_none() && timer.phase() == phase_run.phase => {
+                    timer.run = run;
+                }
+                _ => {
+                    drop(state);
+                    if let Some(run) = run {
+                        persist::discard_run(&app_handle, &phase_run.task_id, run).await;
+                    }
+                }
+            }
+        }
+
+        // 알림 설정도 체크포인트 주기로 갱신 (DB 전환, 외부 변경 반영)
+        if let Some(checkpoints) = checkpoints {
+            persist::save_checkpoints(&app_handle, checkpoints).await;
+            let notify = persist::load_notify_config(&app_handle).await;
+            timer_state.lock().await.notify = notify;
+        }
+    }
+}
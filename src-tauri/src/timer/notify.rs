@@ -0,0 +1,188 @@
+use rusqlite::Connection;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+use super::cycle::TimerPhase;
+use super::TaskTimer;
+use crate::settings;
+
+/// 플랫폼 기본 알림 소리
+#[cfg(target_os = "macos")]
+const NOTIFICATION_SOUND: &str = "NSUserNotificationDefaultSoundName";
+#[cfg(target_os = "windows")]
+const NOTIFICATION_SOUND: &str = "Default";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const NOTIFICATION_SOUND: &str = "message-new-instant";
+
+/// 기본 알림 시점 (5분 전, 1분 전, 종료 시)
+const DEFAULT_WARNING_MINUTES: [u64; 3] = [5, 1, 0];
+
+/// 타이머 알림 설정
+///
+/// `timer_warning_minutes`: 종료 전 알림 시점 (분 목록, 0은 종료 시점)
+/// `notification_sound`: 알림 소리 여부
+#[derive(Debug, Clone)]
+pub struct NotifyConfig {
+    /// 종료 전 알림 시점 (초, 내림차순)
+    pub warning_secs: Vec<u64>,
+    /// 종료 시점 알림 여부
+    pub on_end: bool,
+    pub sound: bool,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        Self::new(&DEFAULT_WARNING_MINUTES, true)
+    }
+}
+
+impl NotifyConfig {
+    /// 설정값 로드 (없거나 잘못된 값은 기본값 사용)
+    pub fn load(conn: &Connection) -> Self {
+        let minutes: Vec<u64> = settings::get_int_list(conn, "timer_warning_minutes")
+            .into_iter()
+            .map(|m| m.max(0) as u64)
+            .collect();
+        let sound = settings::get_bool(conn, "notification_sound");
+
+        Self::new(&minutes, sound)
+    }
+
+    fn new(minutes: &[u64], sound: bool) -> Self {
+        let mut warning_secs: Vec<u64> =
+            minutes.iter().filter(|m| **m > 0).map(|m| m * 60).collect();
+        warning_secs.sort_unstable_by(|a, b| b.cmp(a));
+        warning_secs.dedup();
+
+        Self {
+            warning_secs,
+            on_end: minutes.contains(&0),
+            sound,
+        }
+    }
+
+    /// 이전 tick 이후 지나간 알림 시점 중 가장 가까운 값 (초)
+    pub fn crossed_warning(&self, previous_secs: u64, remaining_secs: u64) -> Option<u64> {
+        self.warning_secs
+            .iter()
+            .copied()
+            .filter(|t| previous_secs > *t && remaining_secs <= *t)
+            .min()
+    }
+}
+
+/// 네이티브 알림 전송 (창이 트레이로 숨겨져 있어도 표시됨)
+pub fn send(app: &AppHandle, config: &NotifyConfig, title: &str, body: &str) {
+    let mut builder = app.notification().builder().title(title).body(body);
+    if config.sound {
+        builder = builder.sound(NOTIFICATION_SOUND);
+    }
+
+    if let Err(e) = builder.show() {
+        log::warn!("[Timer] Failed to send notification: {}", e);
+    }
+}
+
+/// 종료 전 알림
+pub fn send_warning(app: &AppHandle, config: &NotifyConfig, timer: &TaskTimer, secs: u64) {
+    let minutes = secs / 60;
+    let (title, body) = if timer.phase().is_break() {
+        (
+            format!("☕ 휴식 {}분 남음", minutes),
+            format!("휴식이 {}분 뒤 끝납니다.", minutes),
+        )
+    } else {
+        (
+            format!("⏳ {}분 남음", minutes),
+            format!(
+                "\"{}\" 작업 시간이 {}분 남았습니다.",
+                timer.task_title, minutes
+            ),
+        )
+    };
+    send(app, config, &title, &body);
+}
+
+/// 종료 알림 (초과 시간 모드는 계속 측정 중임을 안내)
+pub fn send_ended(app: &AppHandle, config: &NotifyConfig, timer: &TaskTimer) {
+    if !config.on_end {
+        return;
+    }
+    let body = if timer.in_overtime() {
+        format!(
+            "\"{}\" 작업 시간이 종료되었습니다. 초과 시간을 측정합니다.",
+            timer.task_title
+        )
+    } else {
+        format!("\"{}\" 작업 시간이 종료되었습니다.", timer.task_title)
+    };
+    send(app, config, "⏰ 시간 종료!", &body);
+}
+
+/// 사이클 단계 전환 알림
+pub fn send_phase_changed(app: &AppHandle, config: &NotifyConfig, timer: &TaskTimer) {
+    if !config.on_end {
+        return;
+    }
+    let Some(cycle) = &timer.cycle else {
+        return;
+    };
+    let minutes = timer.remaining_secs().div_ceil(60);
+    let (title, body) = match cycle.phase {
+        TimerPhase::Focus => (
+            "🎯 집중 시간".to_string(),
+            format!(
+                "휴식이 끝났습니다. \"{}\" 작업을 {}분 이어가세요.",
+                timer.task_title, minutes
+            ),
+        ),
+        TimerPhase::ShortBreak | TimerPhase::LongBreak => (
+            "☕ 휴식 시간".to_string(),
+            format!(
+                "집중 {}/{} 완료. {}분 휴식하세요.",
+                cycle.focus_completed, cycle.config.intervals, minutes
+            ),
+        ),
+    };
+    send(app, config, &title, &body);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sorts_and_dedups_warning_minutes() {
+        let config = NotifyConfig::new(&[1, 5, 0, 5, 10], false);
+
+        assert_eq!(config.warning_secs, vec![600, 300, 60]);
+        assert!(config.on_end);
+    }
+
+    #[test]
+    fn new_without_zero_disables_end_notification() {
+        let config = NotifyConfig::new(&[5], true);
+
+        assert_eq!(config.warning_secs, vec![300]);
+        assert!(!config.on_end);
+    }
+
+    #[test]
+    fn crossed_warning_fires_once_per_threshold() {
+        let config = NotifyConfig::new(&[5, 1], false);
+
+        assert_eq!(config.crossed_warning(301, 300), Some(300));
+        // 같은 시점을 이미 지났으면 다시 알리지 않음
+        assert_eq!(config.crossed_warning(300, 299), None);
+        assert_eq!(config.crossed_warning(200, 150), None);
+        assert_eq!(config.crossed_warning(61, 60), Some(60));
+    }
+
+    #[test]
+    fn crossed_warning_picks_nearest_when_skipping_thresholds() {
+        let config = NotifyConfig::new(&[5, 1], false);
+
+        // 창이 잠들어 tick이 건너뛰면 가장 가까운 시점만 알림
+        assert_eq!(config.crossed_warning(400, 30), Some(60));
+    }
+}
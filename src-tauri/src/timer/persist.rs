@@ -0,0 +1,308 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::Connection;
+use tauri::{AppHandle, Manager};
+
+use super::cycle::{CycleConfig, TimerCycle, TimerPhase};
+use super::notify::NotifyConfig;
+use super::{TaskTimer, TimerMode};
+use crate::db::models::TimerCheckpoint;
+use crate::db::timer_store::{self, DB_DATETIME_FORMAT};
+use crate::db::DbState;
+use crate::error::AppResult;
+use crate::settings;
+
+/// 체크포인트 저장 주기 (초)
+pub const CHECKPOINT_INTERVAL_SECS: u64 = 15;
+
+/// 타이머에 연결된 실행 기록 (tbl_task_run_history)
+#[derive(Debug, Clone)]
+pub struct TimerRun {
+    pub id: String,
+    pub started_at: DateTime<Utc>,
+}
+
+pub fn format_db_datetime(value: DateTime<Utc>) -> String {
+    value.format(DB_DATETIME_FORMAT).to_string()
+}
+
+pub fn parse_db_datetime(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, DB_DATETIME_FORMAT)
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// 현재 DB에서 읽기 작업 실행 (작업 스레드에서 실행되므로 호출한 런타임 스레드를 막지 않음)
+pub(super) async fn read_db<T, F>(app: &AppHandle, f: F) -> AppResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+{
+    app.state::<DbState>().run(app, f).await
+}
+
+/// 현재 DB에 쓰기 작업 실행 (읽기 전용 DB면 `DB_READ_ONLY`)
+pub(super) async fn write_db<T, F>(app: &AppHandle, f: F) -> AppResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut Connection) -> AppResult<T> + Send + 'static,
+{
+    app.state::<DbState>().run_mut(app, f).await
+}
+
+/// 사이클 모드 설정 로드 (DB 미설정 시 기본값)
+pub async fn load_cycle_config(app: &AppHandle) -> CycleConfig {
+    read_db(app, |conn| Ok(CycleConfig::load(conn)))
+        .await
+        .unwrap_or_default()
+}
+
+/// 타이머 알림 설정 로드 (DB 미설정 시 기본값)
+pub async fn load_notify_config(app: &AppHandle) -> NotifyConfig {
+    read_db(app, |conn| Ok(NotifyConfig::load(conn)))
+        .await
+        .unwrap_or_default()
+}
+
+/// 사이클 모드 사용 여부 로드 (DB 미설정 시 false)
+pub async fn load_cycle_enabled(app: &AppHandle) -> bool {
+    read_db(app, |conn| {
+        Ok(settings::get_bool(conn, "timer_cycle_enabled"))
+    })
+    .await
+    .unwrap_or(false)
+}
+
+/// 카운트다운 시작 설정 결정 (사이클, 남은 시간, 타이머 모드)
+///
+/// cycle이 없으면 timer_cycle_enabled 설정을 따르고, 새 사이클은 첫 집중 구간 길이로 시작한다.
+/// 실행 중인 타이머의 사이클 진행 상태와 모드는 유지한다.
+pub async fn resolve_countdown(
+    app: &AppHandle,
+    existing: Option<&TaskTimer>,
+    cycle: Option<bool>,
+    remaining_secs: u64,
+) -> (Option<TimerCycle>, u64, TimerMode) {
+    let cycle_enabled = match cycle {
+        Some(cycle) => cycle,
+        None => load_cycle_enabled(app).await,
+    };
+    let (cycle, remaining_secs) = match (cycle_enabled, existing.and_then(|t| t.cycle.clone())) {
+        (false, _) => (None, remaining_secs),
+        (true, Some(cycle)) => (Some(cycle), remaining_secs),
+        (true, None) => {
+            let cycle = TimerCycle::new(load_cycle_config(app).await);
+            let focus_secs = cycle.config.focus_secs;
+            (Some(cycle), focus_secs)
+        }
+    };
+
+    // 사이클 모드는 단계 전환이 우선, 실행 중인 타이머는 모드 유지, 새 타이머는 설정값 사용
+    let mode = match (&cycle, existing) {
+        (Some(_), _) => TimerMode::Countdown,
+        (None, Some(t)) => t.mode,
+        (None, None) => load_timer_mode(app).await,
+    };
+
+    (cycle, remaining_secs, mode)
+}
+
+/// 새 타이머 모드 로드 (초과 시간 설정이 켜져 있으면 Overtime)
+pub async fn load_timer_mode(app: &AppHandle) -> TimerMode {
+    let overtime = read_db(app, |conn| {
+        Ok(settings::get_bool(conn, "timer_overtime_enabled"))
+    })
+    .await
+    .unwrap_or(false);
+
+    if overtime {
+        TimerMode::Overtime
+    } else {
+        TimerMode::Countdown
+    }
+}
+
+/// 실행 기록 시작 (DB 미설정 시 None)
+pub async fn begin_run(
+    app: &AppHandle,
+    task_id: &str,
+    phase: TimerPhase,
+    started_at: DateTime<Utc>,
+) -> Option<TimerRun> {
+    let run_task_id = task_id.to_string();
+    let result = write_db(app, move |conn| {
+        timer_store::begin_run(
+            conn,
+            &run_task_id,
+            phase.as_str(),
+            &format_db_datetime(started_at),
+        )
+    })
+    .await;
+
+    match result {
+        Ok(id) => Some(TimerRun { id, started_at }),
+        Err(e) => {
+            log::error!("[Timer] Failed to begin run for {}: {}", task_id, e);
+            None
+        }
+    }
+}
+
+/// 실행 기록 종료 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RunEndKind {
+    /// 정지/일시정지/완료 (end_type 그대로 기록)
+    Finish,
+    /// 카운트다운 종료 (남은 시간 0, PAUSED 전환, 액션 히스토리 기록)
+    Expire,
+}
+
+/// 실행 기록 종료에 필요한 값
+///
+/// 타이머 잠금을 잡은 동안 계산하고, 잠금을 푼 뒤 저장한다.
+#[derive(Debug, Clone)]
+pub struct RunEnd {
+    task_id: String,
+    run_id: Option<String>,
+    end_type: &'static str,
+    kind: RunEndKind,
+    ended_at: String,
+    duration: i64,
+    overtime: i64,
+}
+
+impl RunEnd {
+    /// 정지/일시정지/완료 (end_type: paused, completed 등)
+    pub fn finish(timer: &TaskTimer, end_type: &'static str) -> Self {
+        Self {
+            task_id: timer.task_id.clone(),
+            run_id: timer.run.as_ref().map(|r| r.id.clone()),
+            end_type,
+            kind: RunEndKind::Finish,
+            ended_at: format_db_datetime(timer.run_ended_at()),
+            duration: timer.run_duration_secs(),
+            overtime: timer.overtime_secs() as i64,
+        }
+    }
+
+    /// 카운트다운 종료 (사이클 모드는 마지막 단계까지 마친 것이므로 completed)
+    pub fn expire(timer: &TaskTimer) -> Self {
+        let end_type = if timer.cycle.is_some() {
+            "completed"
+        } else {
+            "timeout"
+        };
+        Self {
+            kind: RunEndKind::Expire,
+            overtime: 0,
+            ..Self::finish(timer, end_type)
+        }
+    }
+
+    /// 카운트다운 종료 여부 (task 상태가 바뀌므로 트레이 메뉴 갱신 필요)
+    pub fn is_expire(&self) -> bool {
+        self.kind == RunEndKind::Expire
+    }
+
+    /// 실행 기록 저장 (다른 DB 작업과 같은 연결에서 이어서 저장할 때 사용)
+    pub fn save(&self, conn: &Connection) -> AppResult<()> {
+        match self.kind {
+            RunEndKind::Finish => timer_store::finish_run(
+                conn,
+                &self.task_id,
+                self.run_id.as_deref(),
+                self.end_type,
+                &self.ended_at,
+                self.duration,
+                self.overtime,
+            ),
+            RunEndKind::Expire => timer_store::expire_timer(
+                conn,
+                &self.task_id,
+                self.run_id.as_deref(),
+                self.end_type,
+                &self.ended_at,
+                self.duration,
+            ),
+        }
+    }
+}
+
+/// 실행 기록 종료 저장 (실패 시 로그만 남김)
+pub async fn finish_runs(app: &AppHandle, ends: Vec<RunEnd>) {
+    if ends.is_empty() {
+        return;
+    }
+
+    let result = write_db(app, move |conn| {
+        for end in &ends {
+            if let Err(e) = end.save(conn) {
+                log::error!("[Timer] Failed to finish run for {}: {}", end.task_id, e);
+            }
+        }
+        Ok(())
+    })
+    .await;
+    if let Err(e) = result {
+        log::error!("[Timer] Failed to finish runs: {}", e);
+    }
+}
+
+/// 타이머에 연결하지 못한 실행 기록 종료 (기록 시작 중 타이머가 정지되거나 교체된 경우)
+pub async fn discard_run(app: &AppHandle, task_id: &str, run: TimerRun) {
+    let ended_at = Utc::now();
+    let end = RunEnd {
+        task_id: task_id.to_string(),
+        run_id: Some(run.id),
+        end_type: "paused",
+        kind: RunEndKind::Finish,
+        ended_at: format_db_datetime(ended_at),
+        duration: (ended_at - run.started_at).num_seconds().max(0),
+        overtime: 0,
+    };
+    finish_runs(app, vec![end]).await;
+}
+
+/// 실행 중인 타이머 체크포인트 (타이머 잠금을 잡은 동안 계산)
+pub fn checkpoints<'a>(
+    timers: impl IntoIterator<Item = &'a TaskTimer>,
+) -> Vec<(TimerCheckpoint, i64)> {
+    let now = format_db_datetime(Utc::now());
+    timers
+        .into_iter()
+        .map(|timer| {
+            let checkpoint = TimerCheckpoint {
+                task_id: timer.task_id.clone(),
+                run_id: timer.run.as_ref().map(|r| r.id.clone()),
+                task_title: timer.task_title.clone(),
+                deadline_at: format_db_datetime(timer.deadline_at()),
+                remaining_seconds: timer.remaining_secs() as i64,
+                cycle: timer
+                    .cycle
+                    .as_ref()
+                    .and_then(|c| serde_json::to_string(c).ok()),
+                mode: timer.mode.as_str().to_string(),
+                overtime_seconds: timer.overtime_secs() as i64,
+                elapsed_seconds: timer.elapsed_secs() as i64,
+                run_started_at: timer.run.as_ref().map(|r| format_db_datetime(r.started_at)),
+                checkpointed_at: now.clone(),
+            };
+            (checkpoint, timer.run_duration_secs())
+        })
+        .collect()
+}
+
+/// 체크포인트 저장
+pub async fn save_checkpoints(app: &AppHandle, checkpoints: Vec<(TimerCheckpoint, i64)>) {
+    if checkpoints.is_empty() {
+        return;
+    }
+
+    let result = write_db(app, move |conn| {
+        timer_store::save_checkpoints(conn, &checkpoints)
+    })
+    .await;
+    if let Err(e) = result {
+        log::error!("[Timer] Failed to save checkpoints: {}", e);
+    }
+}
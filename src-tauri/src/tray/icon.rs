@@ -0,0 +1,123 @@
+use std::f32::consts::TAU;
+use std::sync::Mutex;
+
+use tauri::image::Image;
+use tauri::AppHandle;
+
+/// 진행 아이콘 크기 (px, 메뉴 막대 22pt @2x)
+const ICON_SIZE: u32 = 44;
+
+/// 링 두께 (px)
+const RING_WIDTH: f32 = 6.0;
+
+/// 진행률 단계 수 (같은 단계면 아이콘을 다시 설정하지 않음)
+const PROGRESS_STEPS: f32 = 100.0;
+
+/// 진행하지 않은 구간의 불투명도
+const TRACK_ALPHA: f32 = 0.3;
+
+/// 진행 아이콘 종류 (링 색상)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrayIconStyle {
+    Focus,
+    Break,
+    Overtime,
+    Stopwatch,
+}
+
+impl TrayIconStyle {
+    fn color(&self) -> [u8; 3] {
+        match self {
+            Self::Focus => [0x4c, 0x8b, 0xf5],
+            Self::Break => [0x34, 0xa8, 0x53],
+            Self::Overtime => [0xea, 0x43, 0x35],
+            Self::Stopwatch => [0xf5, 0xa6, 0x23],
+        }
+    }
+}
+
+/// 트레이 아이콘에 표시할 진행 상태
+#[derive(Debug, Clone, Copy)]
+pub struct TrayProgress {
+    /// 진행률 (0.0 ~ 1.0)
+    pub fraction: f32,
+    pub style: TrayIconStyle,
+}
+
+impl TrayProgress {
+    fn step(&self) -> u16 {
+        (self.fraction.clamp(0.0, 1.0) * PROGRESS_STEPS).round() as u16
+    }
+}
+
+/// 마지막으로 설정한 아이콘
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IconKey {
+    Idle,
+    Progress(TrayIconStyle, u16),
+}
+
+static LAST_ICON: Mutex<Option<IconKey>> = Mutex::new(None);
+
+// 진행 링 아이콘 그리기 (12시 방향부터 시계 방향으로 채움)
+pub fn render_progress_icon(progress: &TrayProgress) -> Image<'static> {
+    let size = ICON_SIZE as f32;
+    let center = size / 2.0;
+    let radius = center - RING_WIDTH / 2.0 - 1.0;
+    let fraction = progress.step() as f32 / PROGRESS_STEPS;
+    let [r, g, b] = progress.style.color();
+
+    let mut rgba = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let dx = x as f32 + 0.5 - center;
+            let dy = y as f32 + 0.5 - center;
+
+            // 링 경계는 1px 폭으로 부드럽게 처리
+            let distance = (dx * dx + dy * dy).sqrt();
+            let coverage = (RING_WIDTH / 2.0 + 0.5 - (distance - radius).abs()).clamp(0.0, 1.0);
+            if coverage <= 0.0 {
+                rgba.extend_from_slice(&[0, 0, 0, 0]);
+                continue;
+            }
+
+            // 12시 방향 0, 시계 방향으로 증가
+            let angle = dx.atan2(-dy).rem_euclid(TAU) / TAU;
+            let alpha = if angle < fraction { 1.0 } else { TRACK_ALPHA };
+            rgba.extend_from_slice(&[r, g, b, (coverage * alpha * 255.0).round() as u8]);
+        }
+    }
+
+    Image::new_owned(rgba, ICON_SIZE, ICON_SIZE)
+}
+
+// 트레이 아이콘 업데이트 (진행 중이 아니면 기본 템플릿 아이콘)
+// 진행률이 바뀐 경우에만 아이콘을 다시 설정
+pub fn update_tray_icon(app: &AppHandle, progress: Option<TrayProgress>) {
+    let key = match &progress {
+        Some(p) => IconKey::Progress(p.style, p.step()),
+        None => IconKey::Idle,
+    };
+    let Ok(mut last) = LAST_ICON.lock() else {
+        return;
+    };
+    if *last == Some(key) {
+        return;
+    }
+    let Some(tray) = app.tray_by_id("main") else {
+        return;
+    };
+
+    let result = match &progress {
+        Some(p) => tray
+            .set_icon(Some(render_progress_icon(p)))
+            .and_then(|_| tray.set_icon_as_template(false)),
+        None => tray
+            .set_icon(app.default_window_icon().cloned())
+            .and_then(|_| tray.set_icon_as_template(true)),
+    };
+    match result {
+        Ok(()) => *last = Some(key),
+        Err(e) => log::warn!("[Tray] Failed to set icon: {}", e),
+    }
+}
@@ -0,0 +1,199 @@
+use rusqlite::Connection;
+use tauri::menu::{
+    CheckMenuItem, IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu,
+};
+use tauri::{AppHandle, Manager, Wry};
+
+use super::{show_main_window, truncate_title};
+use crate::db::timer_store::{list_active_tasks, ActiveTask};
+use crate::db::{activate_workspace, load_config, sidebar_counts, DbState, SidebarCounts};
+use crate::timer::actions;
+
+/// 트레이 메뉴에 표시할 최대 task 수
+const MAX_MENU_TASKS: usize = 10;
+
+/// 트레이 메뉴의 시간 추가 단위 (분)
+const EXTEND_MINUTES: i64 = 5;
+
+/// 메뉴에 표시할 DB 내용 (오늘 마감 개수, 진행 중/일시정지 task)
+#[derive(Default)]
+pub struct MenuContents {
+    counts: Option<SidebarCounts>,
+    tasks: Vec<ActiveTask>,
+}
+
+impl MenuContents {
+    fn load(conn: &Connection) -> Self {
+        Self {
+            counts: sidebar_counts(conn).ok(),
+            tasks: list_active_tasks(conn).unwrap_or_default(),
+        }
+    }
+}
+
+// 트레이 메뉴 생성 (앱 시작 시)
+// DB를 열기 전이므로 task 없이 만들고, `get_db_status`/`init_db`에서 DB를 연 뒤 `refresh_menu`로 채움
+pub fn build_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+    menu_with(app, &MenuContents::default())
+}
+
+// DB 내용으로 트레이 메뉴 생성
+// DB가 설정되어 있으면 오늘 마감 개수와 진행 중/일시정지 task 목록을 함께 표시
+fn menu_with(app: &AppHandle, contents: &MenuContents) -> tauri::Result<Menu<Wry>> {
+    let menu = Menu::new(app)?;
+
+    if let Some(counts) = &contents.counts {
+        let today = MenuItem::with_id(
+            app,
+            "today",
+            format!("오늘 마감 {}개", counts.today),
+            false,
+            None::<&str>,
+        )?;
+        menu.append(&today)?;
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+    }
+
+    for task in contents.tasks.iter().take(MAX_MENU_TASKS) {
+        menu.append(&task_submenu(app, task)?)?;
+    }
+    if !contents.tasks.is_empty() {
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+    }
+
+    if let Some(workspaces) = workspace_submenu(app)? {
+        menu.append(&workspaces)?;
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+    }
+
+    let show_item = MenuItem::with_id(app, "show", "앱 열기", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", "종료", true, None::<&str>)?;
+    menu.append(&show_item)?;
+    menu.append(&quit_item)?;
+
+    Ok(menu)
+}
+
+// task별 하위 메뉴 (일시정지/재개, 완료, +5분)
+fn task_submenu(app: &AppHandle, task: &ActiveTask) -> tauri::Result<Submenu<Wry>> {
+    let running = task.status == "IN_PROGRESS";
+    let label = format!(
+        "{} {}",
+        if running { "▶" } else { "⏸" },
+        truncate_title(&task.title, 20)
+    );
+
+    let toggle = if running {
+        MenuItem::with_id(
+            app,
+            format!("task-pause:{}", task.id),
+            "일시정지",
+            true,
+            None::<&str>,
+        )?
+    } else {
+        MenuItem::with_id(
+            app,
+            format!("task-resume:{}", task.id),
+            "재개",
+            true,
+            None::<&str>,
+        )?
+    };
+    let complete = MenuItem::with_id(
+        app,
+        format!("task-complete:{}", task.id),
+        "완료",
+        true,
+        None::<&str>,
+    )?;
+    // 예상 시간이 없는 task(스톱워치)는 시간 추가 불가
+    let extend = MenuItem::with_id(
+        app,
+        format!("task-extend:{}", task.id),
+        format!("+{}분", EXTEND_MINUTES),
+        task.expected_duration.is_some(),
+        None::<&str>,
+    )?;
+
+    let items: [&dyn IsMenuItem<Wry>; 3] = [&toggle, &complete, &extend];
+    Submenu::with_items(app, label, true, &items)
+}
+
+// 워크스페이스 전환 하위 메뉴 (등록된 워크스페이스가 없으면 None)
+fn workspace_submenu(app: &AppHandle) -> tauri::Result<Option<Submenu<Wry>>> {
+    let Ok(config) = load_config(app) else {
+        return Ok(None);
+    };
+    if config.workspaces.is_empty() {
+        return Ok(None);
+    }
+
+    let submenu = Submenu::new(app, "워크스페이스", true)?;
+    for workspace in &config.workspaces {
+        let active = config.active_workspace_id.as_deref() == Some(workspace.id.as_str());
+        let item = CheckMenuItem::with_id(
+            app,
+            format!("workspace:{}", workspace.id),
+            truncate_title(&workspace.name, 20),
+            // 사용 중인 워크스페이스는 선택 불가
+            !active,
+            active,
+            None::<&str>,
+        )?;
+        submenu.append(&item)?;
+    }
+    Ok(Some(submenu))
+}
+
+// 트레이 메뉴 다시 생성 (task 변경 시 호출)
+pub fn refresh_menu(app: &AppHandle) {
+    let app = app.clone();
+    // 메뉴 생성은 메인 스레드에서 처리되므로 호출한 스레드를 막지 않도록 분리
+    tauri::async_runtime::spawn(async move {
+        let Some(tray) = app.tray_by_id("main") else {
+            return;
+        };
+        let contents = app
+            .state::<DbState>()
+            .run(&app, |conn| Ok(MenuContents::load(conn)))
+            .await
+            .unwrap_or_default();
+        match menu_with(&app, &contents) {
+            Ok(menu) => {
+                let _ = tray.set_menu(Some(menu));
+            }
+            Err(e) => log::warn!("[Tray] Failed to build menu: {}", e),
+        }
+    });
+}
+
+// 트레이 메뉴 이벤트 처리
+pub fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id.as_ref() {
+        "show" => show_main_window(app),
+        "quit" => app.exit(0),
+        id => {
+            let Some((action, target)) = id.split_once(':') else {
+                return;
+            };
+            let app = app.clone();
+            let action = action.to_string();
+            let target = target.to_string();
+
+            tauri::async_runtime::spawn(async move {
+                let result = match action.as_str() {
+                    "task-pause" => actions::pause_task(&app, &target).await,
+                    "task-resume" => actions::resume_task(&app, &target).await,
+                    "task-complete" => actions::complete_task(&app, &target).await,
+                    "task-extend" => actions::extend_task(&app, &target, EXTEND_MINUTES).await,
+                    "workspace" => activate_workspace(&app, &target, None).await.map(|_| ()),
+                    _ => Ok(()),
+                };
+                if let Err(e) = result {
+                    log::warn!("[Tray] {} failed for {}: {}", action, target, e);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,60 @@
+pub mod icon;
+pub mod menu;
+
+pub use icon::{update_tray_icon, TrayIconStyle, TrayProgress};
+pub use menu::{build_menu, handle_menu_event, refresh_menu};
+
+use tauri::{AppHandle, Manager};
+
+/// 실행 중인 타이머가 없을 때 트레이에 표시할 기본 타이틀
+pub const DEFAULT_TRAY_TITLE: &str = "미루미";
+
+// 메인 창 표시 (트레이에서 호출)
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        // 숨겨진 창 표시
+        let _ = window.show();
+        // 최소화된 경우 복원
+        let _ = window.unminimize();
+        // 포커스 설정
+        let _ = window.set_focus();
+    }
+}
+
+// 트레이 타이틀 포맷
+pub fn format_tray_title(task_title: &str, seconds: u64) -> String {
+    with_task_title(task_title, format_mm_ss(seconds))
+}
+
+// 초과 시간 트레이 타이틀 포맷 (+MM:SS)
+pub fn format_tray_overtime_title(task_title: &str, seconds: u64) -> String {
+    with_task_title(task_title, format!("+{}", format_mm_ss(seconds)))
+}
+
+fn format_mm_ss(seconds: u64) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn with_task_title(task_title: &str, time: String) -> String {
+    if !task_title.is_empty() {
+        format!("{} {}", truncate_title(task_title, 12), time)
+    } else {
+        format!("⏱ {}", time)
+    }
+}
+
+// 긴 제목 말줄임
+pub fn truncate_title(title: &str, max_len: usize) -> String {
+    if title.chars().count() > max_len {
+        format!("{}…", title.chars().take(max_len).collect::<String>())
+    } else {
+        title.to_string()
+    }
+}
+
+// 트레이 업데이트 헬퍼
+pub fn update_tray(app: &AppHandle, title: &str) {
+    if let Some(tray) = app.tray_by_id("main") {
+        let _ = tray.set_title(Some(title));
+    }
+}
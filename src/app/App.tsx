@@ -4,7 +4,6 @@ import { MainPage, OnboardingPage, SettingsPage } from "@pages/index";
 import { TaskDetailPage } from "@pages/TaskDetailPage";
 import { TaskWidgetPage } from "@pages/TaskWidgetPage";
 import { useDbStatus, useTasks } from "@shared/hooks";
-import { stopTrayTimer } from "@shared/lib/tray";
 
 // 앱 상태 타입
 type AppState = "loading" | "onboarding" | "ready";
@@ -27,19 +26,14 @@ const AppRoot = () => {
   const { status, loading } = useDbStatus();
   const [appState, setAppState] = useState<AppState>("loading");
 
-  // 앱 시작 시 트레이 타이머 초기화 (방어적 코드)
-  useEffect(() => {
-    stopTrayTimer(true).catch(console.error);
-  }, []);
-
   useEffect(() => {
     if (loading) {
       setAppState("loading");
       return;
     }
 
-    // DB 미설정 또는 파일 없음 → 온보딩
-    if (!status?.configured || !status?.exists) {
+    // DB 미설정, 파일 없음 또는 암호 입력 전 → 온보딩
+    if (!status?.configured || !status?.exists || status?.needsPassphrase) {
       setAppState("onboarding");
       return;
     }
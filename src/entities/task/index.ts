@@ -5,6 +5,7 @@ export {
   type TaskMemo,
   type TaskNote,
   type TaskRunHistory,
+  type TimerPhase,
   type TimeExtensionHistory,
   type TaskActionHistory,
 } from "./types";
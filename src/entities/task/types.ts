@@ -28,6 +28,9 @@ export interface TaskNote {
   updatedAt?: Date;
 }
 
+/** 타이머 단계 (일반 타이머는 항상 focus) */
+export type TimerPhase = "focus" | "short_break" | "long_break";
+
 /** 실행 히스토리 항목 */
 export interface TaskRunHistory {
   id: string;
@@ -37,6 +40,10 @@ export interface TaskRunHistory {
   duration: number;
   /** 종료 타입: 완료, 일시정지, 타이머종료 */
   endType: "completed" | "paused" | "timeout" | "interrupted";
+  /** 실행 단계: 집중, 짧은 휴식, 긴 휴식 (사이클 모드) */
+  phase: TimerPhase;
+  /** 카운트다운 종료 후 초과 시간 (초 단위) */
+  overtimeSeconds: number;
 }
 
 /** 시간 추가 히스토리 항목 */
@@ -57,8 +64,8 @@ export interface TimeExtensionHistory {
 /** 액션 히스토리 항목 (모든 상태 변경 기록) */
 export interface TaskActionHistory {
   id: string;
-  /** 액션 타입: CREATED, STARTED, PAUSED, COMPLETED, ARCHIVED, RESTORED, TARGET_DATE_CHANGED */
-  actionType: "CREATED" | "STARTED" | "PAUSED" | "COMPLETED" | "ARCHIVED" | "RESTORED" | "STATUS_CHANGED" | "TARGET_DATE_CHANGED";
+  /** 액션 타입: CREATED, STARTED, PAUSED, COMPLETED, ARCHIVED, RESTORED, TARGET_DATE_CHANGED, TIMER_ENDED */
+  actionType: "CREATED" | "STARTED" | "PAUSED" | "COMPLETED" | "ARCHIVED" | "RESTORED" | "STATUS_CHANGED" | "TARGET_DATE_CHANGED" | "TIMER_ENDED";
   /** 이전 상태 */
   previousStatus?: string;
   /** 새 상태 */
@@ -53,8 +53,10 @@ export const TaskItem = ({
     isHovered,
     editingTitle,
     titleInputRef,
-    remainingTimeMs,
     remainingTimeSeconds,
+    isStopwatch,
+    displayTimeMs,
+    phase,
     progress,
     completedProgress,
     urgencyLevel,
@@ -107,7 +109,7 @@ export const TaskItem = ({
 
   // 계산된 값들 - DB 기반 남은 시간으로 소비시간/전체시간 형식 표시
   const expectedDurationText = formatDurationWithSpent(
-    task.expectedDuration ?? 5,
+    task.expectedDuration,
     task.remainingTimeSeconds
   );
   const targetDate = task.targetDate ?? new Date();
@@ -450,26 +452,35 @@ export const TaskItem = ({
                             : "text-gray-400"
                       }`}
                     >
-                      {formatTimeMs(remainingTimeMs)}
+                      {formatTimeMs(displayTimeMs)}
                     </span>
+                    {/* 사이클 모드 휴식 구간 표시 */}
+                    {phase !== "focus" && (
+                      <span className="text-[10px] text-emerald-400">
+                        {phase === "long_break" ? "긴 휴식" : "휴식"}
+                      </span>
+                    )}
                   </div>
                   
-                  <motion.div
-                    className="flex items-center gap-1"
-                    initial={{ opacity: 0 }}
-                    animate={{ opacity: isHovered ? 1 : 0 }}
-                    transition={{ duration: 0.15 }}
-                  >
-                    {[1, 5].map((min) => (
-                      <button
-                        key={min}
-                        onClick={(e) => handleQuickExtendTime(e, min)}
-                        className="px-1.5 py-0.5 text-[9px] rounded bg-white/[0.03] hover:bg-white/[0.08] text-gray-500 hover:text-gray-300 transition-colors border border-white/[0.03]"
-                      >
-                        +{min}m
-                      </button>
-                    ))}
-                  </motion.div>
+                  {/* 스톱워치는 남은 시간이 없으므로 시간 추가 버튼 숨김 */}
+                  {!isStopwatch && (
+                    <motion.div
+                      className="flex items-center gap-1"
+                      initial={{ opacity: 0 }}
+                      animate={{ opacity: isHovered ? 1 : 0 }}
+                      transition={{ duration: 0.15 }}
+                    >
+                      {[1, 5].map((min) => (
+                        <button
+                          key={min}
+                          onClick={(e) => handleQuickExtendTime(e, min)}
+                          className="px-1.5 py-0.5 text-[9px] rounded bg-white/[0.03] hover:bg-white/[0.08] text-gray-500 hover:text-gray-300 transition-colors border border-white/[0.03]"
+                        >
+                          +{min}m
+                        </button>
+                      ))}
+                    </motion.div>
+                  )}
                 </div>
               </motion.div>
             )}
@@ -481,7 +492,8 @@ export const TaskItem = ({
               <TaskItemPausedInfo
                 lastPausedAt={task.lastPausedAt}
                 remainingTimeSeconds={remainingTimeSeconds}
-                expectedDurationMinutes={task.expectedDuration ?? 5}
+                expectedDurationMinutes={task.expectedDuration ?? null}
+                totalTimeSpentMinutes={task.totalTimeSpent}
                 delayDays={delayDays}
                 tags={task.tags}
                 isHovered={isHovered}
@@ -1,5 +1,5 @@
 import { useState, useCallback, useMemo, useRef, useEffect } from "react";
-import { TaskStatus, TaskMemo, TaskNote, TimeExtensionHistory, Task, TaskRunHistory, TaskActionHistory } from "@entities/task";
+import { TaskStatus, TaskMemo, TaskNote, TimeExtensionHistory, Task, TaskRunHistory, TaskActionHistory, type TimerPhase } from "@entities/task";
 import { useTaskTimer } from "./useTaskTimer";
 import type { UrgencyLevel, UrgencyColors } from "../lib/urgency";
 import type { ModalTabType, StatusChangeOptions } from "../types";
@@ -50,6 +50,9 @@ export interface UseTaskItemReturn {
   // 타이머 관련
   remainingTimeMs: number;
   remainingTimeSeconds: number;
+  isStopwatch: boolean;
+  displayTimeMs: number;
+  phase: TimerPhase;
   progress: number;
   completedProgress: number;
   urgencyLevel: UrgencyLevel;
@@ -119,6 +122,9 @@ export const useTaskItem = ({
   const {
     remainingTimeMs,
     remainingTimeSeconds,
+    isStopwatch,
+    displayTimeMs,
+    phase,
     progress,
     completedProgress,
     urgencyLevel,
@@ -128,7 +134,8 @@ export const useTaskItem = ({
     handleQuickExtendTime,
     extendRemainingTime,
   } = useTaskTimer({
-    expectedDuration: task.expectedDuration ?? 5,
+    taskId: task.id,
+    expectedDuration: task.expectedDuration ?? null,
     defaultDuration,
     savedRemainingTimeSeconds: task.remainingTimeSeconds,
     isInProgress,
@@ -246,7 +253,8 @@ export const useTaskItem = ({
     e.stopPropagation();
     if (timeExtendMinutes === 0) return;
     
-    const currentDuration = task.expectedDuration ?? 5;
+    // 예상 시간이 없던 task는 연장한 시간이 새 예상 시간이 됨
+    const currentDuration = task.expectedDuration ?? 0;
     const newDuration = Math.max(1, currentDuration + timeExtendMinutes);
     const extension: TimeExtensionHistory = {
       id: crypto.randomUUID(),
@@ -403,6 +411,9 @@ export const useTaskItem = ({
     // 타이머 관련
     remainingTimeMs,
     remainingTimeSeconds,
+    isStopwatch,
+    displayTimeMs,
+    phase,
     progress,
     completedProgress,
     urgencyLevel,
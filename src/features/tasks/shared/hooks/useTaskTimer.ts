@@ -1,13 +1,15 @@
 import { useState, useEffect, useCallback, useMemo, useRef } from "react";
 import { listen } from "@tauri-apps/api/event";
-import { TaskStatus, TimeExtensionHistory } from "@entities/task";
+import { TaskStatus, TimeExtensionHistory, type TimerPhase } from "@entities/task";
 import { getUrgencyLevel, getUrgencyColors, type UrgencyLevel, type UrgencyColors } from "../lib/urgency";
-import { startTrayTimer, stopTrayTimer, getRemainingTime, syncTrayTimer } from "@shared/lib/tray";
-import { sendTimerEndedNotification } from "@shared/lib/notification";
+import { startTrayTimer, startTrayStopwatch, stopTrayTimer, getRemainingTime, getElapsedTime, syncTrayTimer, type TimerEndedPayload, type TimerTickPayload, type TimerPhaseChangedPayload } from "@shared/lib/tray";
 import type { StatusChangeOptions } from "../types";
 
 export interface UseTaskTimerProps {
-  expectedDuration: number;
+  /** 태스크 ID - Rust 타이머 엔진에서 task별 타이머를 구분하는 키 */
+  taskId?: string;
+  /** 예상 시간 (분) - 없으면 경과 시간을 세는 스톱워치 */
+  expectedDuration: number | null;
   defaultDuration?: number;
   /** 저장된 남은 시간 (초 단위) - 일시정지 후 재시작 시 사용 */
   savedRemainingTimeSeconds?: number;
@@ -24,6 +26,14 @@ interface UseTaskTimerReturn {
   remainingTimeMs: number;
   /** 남은 시간 (초 단위) */
   remainingTimeSeconds: number;
+  /** 예상 시간이 없어 경과 시간을 세는 스톱워치 */
+  isStopwatch: boolean;
+  /** 스톱워치 경과 시간 (밀리초 단위) */
+  elapsedTimeMs: number;
+  /** 화면에 표시할 시간 (스톱워치는 경과 시간, 그 외에는 남은 시간) */
+  displayTimeMs: number;
+  /** 사이클 모드의 현재 구간 (사이클 모드가 아니면 focus) */
+  phase: TimerPhase;
   isRunning: boolean;
   progress: number;
   completedProgress: number;
@@ -36,6 +46,7 @@ interface UseTaskTimerReturn {
 }
 
 export const useTaskTimer = ({
+  taskId,
   expectedDuration,
   defaultDuration,
   savedRemainingTimeSeconds,
@@ -45,7 +56,10 @@ export const useTaskTimer = ({
   onExtendTime,
   onTimerEnd,
 }: UseTaskTimerProps): UseTaskTimerReturn => {
-  const expectedDurationMs = expectedDuration * 60 * 1000;
+  const isStopwatch = expectedDuration == null && !defaultDuration;
+  // 스톱워치는 남은 시간이 없으므로 카운트다운 계산은 0분 기준
+  const expectedMinutes = expectedDuration ?? 0;
+  const expectedDurationMs = expectedMinutes * 60 * 1000;
   const timerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedDurationMs;
   
   // 저장된 남은 시간이 유효하면 사용 (0보다 크고 null/undefined가 아닐 때)
@@ -66,18 +80,27 @@ export const useTaskTimer = ({
   // 일시정지 시 계산된 남은 시간 (handlePause에서 설정, handlePlay에서 사용)
   // useEffect에서 덮어쓰지 않도록 별도 ref로 관리
   const pausedRemainingMsRef = useRef<number | null>(null);
+
+  // 스톱워치 경과 시간 (Rust 타이머의 timer-tick elapsedSecs 기준)
+  const [elapsedTimeMs, setElapsedTimeMs] = useState(0);
+  const elapsedStartTimeRef = useRef<number | null>(null);
+  const elapsedStartMsRef = useRef(0);
+
+  // 사이클 모드 구간 (timer-phase-changed 기준, 구간이 바뀌면 진행률도 구간 길이 기준)
+  const [phase, setPhase] = useState<TimerPhase>("focus");
+  const [phaseDurationMs, setPhaseDurationMs] = useState<number | null>(null);
   
   // expectedDuration 변경 시 타이머 시간 조정 (pausedRemainingMsRef 보호)
   useEffect(() => {
     if (!isRunning && pausedRemainingMsRef.current === null) {
-      const newTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedDuration * 60 * 1000;
+      const newTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedMinutes * 60 * 1000;
       if (savedRemainingTimeSeconds != null && savedRemainingTimeSeconds > 0) {
         setRemainingTimeMs(savedRemainingTimeSeconds * 1000);
       } else {
         setRemainingTimeMs(newTimerDurationMs);
       }
     }
-  }, [expectedDuration, defaultDuration, savedRemainingTimeSeconds, isRunning]);
+  }, [expectedMinutes, defaultDuration, savedRemainingTimeSeconds, isRunning]);
 
   // DB에서 로드된 savedRemainingTimeSeconds 변경 시 상태 동기화
   useEffect(() => {
@@ -104,18 +127,18 @@ export const useTaskTimer = ({
         });
       } else {
         setRemainingTimeMs((currentMs) => {
-          const newTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedDuration * 60 * 1000;
+          const newTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedMinutes * 60 * 1000;
           if (currentMs <= 0 || currentMs > newTimerDurationMs) {
             return newTimerDurationMs;
           }
           return currentMs;
         });
       }
-    } else {
+    } else if (!isStopwatch) {
       // 실행 중일 때는 Rust 트레이 타이머와 동기화
       const syncWithRustTimer = async () => {
         try {
-          const [remainingSecs, running] = await getRemainingTime();
+          const [remainingSecs, running] = await getRemainingTime(taskId);
           if (running) {
             setRemainingTimeMs(remainingSecs * 1000);
           } else {
@@ -128,19 +151,23 @@ export const useTaskTimer = ({
       };
       syncWithRustTimer();
     }
-  }, [savedRemainingTimeSeconds, isRunning, expectedDuration, defaultDuration]);
+  }, [taskId, savedRemainingTimeSeconds, isRunning, expectedMinutes, defaultDuration, isStopwatch]);
 
   const remainingTimeSeconds = Math.ceil(remainingTimeMs / 1000);
 
   // timerDurationMs를 동적으로 계산 (expectedDuration 변경 시 반영)
   const currentTimerDurationMs = useMemo(() => {
-    return defaultDuration ? defaultDuration * 1000 : expectedDuration * 60 * 1000;
-  }, [expectedDuration, defaultDuration]);
+    return defaultDuration ? defaultDuration * 1000 : expectedMinutes * 60 * 1000;
+  }, [expectedMinutes, defaultDuration]);
 
-  const progress = useMemo(
-    () => remainingTimeMs / currentTimerDurationMs,
-    [remainingTimeMs, currentTimerDurationMs]
-  );
+  // 스톱워치는 종료 시점이 없으므로 항상 가득 찬 상태 (긴급도 normal)
+  const progress = useMemo(() => {
+    const durationMs = phaseDurationMs ?? currentTimerDurationMs;
+    if (isStopwatch || durationMs <= 0) {
+      return 1;
+    }
+    return Math.min(1, remainingTimeMs / durationMs);
+  }, [isStopwatch, remainingTimeMs, currentTimerDurationMs, phaseDurationMs]);
 
   const completedProgress = useMemo(() => 1 - progress, [progress]);
   const urgencyLevel = useMemo(() => getUrgencyLevel(progress), [progress]);
@@ -152,10 +179,12 @@ export const useTaskTimer = ({
     
     const setupListener = async () => {
       try {
-        const unlisten = await listen("timer-ended", () => {
-          if (taskTitle) {
-            sendTimerEndedNotification(taskTitle);
+        const unlisten = await listen<TimerEndedPayload>("timer-ended", (event) => {
+          // 다른 태스크의 타이머 종료 이벤트는 무시
+          if (taskId && event.payload?.taskId !== taskId) {
+            return;
           }
+          // 종료 알림은 창이 숨겨져 있어도 표시되도록 Rust 타이머 엔진이 전송
           onTimerEnd?.();
           setRemainingTimeMs(0);
           setIsRunning(false);
@@ -177,13 +206,50 @@ export const useTaskTimer = ({
         }
       }
     };
-  }, [taskTitle, onTimerEnd]);
+  }, [taskId, onTimerEnd]);
+
+  // 사이클 모드 구간 전환 이벤트 수신 (새 구간의 남은 시간으로 카운트다운 재시작)
+  useEffect(() => {
+    if (!taskId) {
+      return;
+    }
+    let unlistenFn: (() => void) | null = null;
+
+    listen<TimerPhaseChangedPayload>("timer-phase-changed", (event) => {
+      const { taskId: phaseTaskId, phase: nextPhase, remainingSecs } = event.payload;
+      if (phaseTaskId !== taskId) {
+        return;
+      }
+      const phaseMs = remainingSecs * 1000;
+      setPhase(nextPhase);
+      setPhaseDurationMs(phaseMs);
+      timerStartTimeRef.current = Date.now();
+      timerStartRemainingMsRef.current = phaseMs;
+      setRemainingTimeMs(phaseMs);
+    }).then((unlisten) => {
+      unlistenFn = unlisten;
+    });
+
+    return () => {
+      unlistenFn?.();
+    };
+  }, [taskId]);
 
   // 창이 포그라운드로 돌아올 때 Rust 타이머와 동기화
   useEffect(() => {
     const handleVisibilityChange = async () => {
       if (document.visibilityState === "visible" && isRunning) {
-        const [remainingSecs, running] = await getRemainingTime();
+        if (isStopwatch) {
+          const [elapsedSecs, running] = await getElapsedTime(taskId);
+          elapsedStartTimeRef.current = running ? Date.now() : null;
+          elapsedStartMsRef.current = elapsedSecs * 1000;
+          setElapsedTimeMs(elapsedSecs * 1000);
+          if (!running) {
+            setIsRunning(false);
+          }
+          return;
+        }
+        const [remainingSecs, running] = await getRemainingTime(taskId);
         if (running) {
           setRemainingTimeMs(remainingSecs * 1000);
         } else {
@@ -198,23 +264,51 @@ export const useTaskTimer = ({
     return () => {
       document.removeEventListener("visibilitychange", handleVisibilityChange);
     };
-  }, [isRunning]);
+  }, [taskId, isRunning, isStopwatch]);
 
   // 타이머 종료 처리
+  // 실행 기록/상태 저장은 Rust 타이머 엔진이 담당 (timer-ended)
+  // 초과 시간 모드면 Rust 타이머가 0 이후에도 계속 측정하므로 실행 상태 유지
   useEffect(() => {
-    if (remainingTimeMs === 0 && isRunning && !timerEndedRef.current) {
+    if (!isStopwatch && remainingTimeMs === 0 && isRunning && !timerEndedRef.current) {
       timerEndedRef.current = true;
-      setIsRunning(false);
-      stopTrayTimer();
+      getRemainingTime(taskId).then(([, running]) => {
+        if (!running) {
+          setIsRunning(false);
+        }
+      });
     }
     if (remainingTimeMs > 0) {
       timerEndedRef.current = false;
     }
-  }, [remainingTimeMs, isRunning]);
+  }, [taskId, remainingTimeMs, isRunning, isStopwatch]);
+
+  // 스톱워치 실행 중 UI 업데이트 (경과 시간 증가)
+  useEffect(() => {
+    if (!isStopwatch || !isRunning) {
+      elapsedStartTimeRef.current = null;
+      return;
+    }
+    if (elapsedStartTimeRef.current === null) {
+      elapsedStartTimeRef.current = Date.now();
+      elapsedStartMsRef.current = elapsedTimeMs;
+    }
+
+    const localTimer = setInterval(() => {
+      if (elapsedStartTimeRef.current === null) {
+        return;
+      }
+      setElapsedTimeMs(elapsedStartMsRef.current + (Date.now() - elapsedStartTimeRef.current));
+    }, 100);
+
+    return () => {
+      clearInterval(localTimer);
+    };
+  }, [isStopwatch, isRunning]);
 
   // 실행 중일 때 센티초 단위 UI 업데이트 및 Rust 타이머 동기화
   useEffect(() => {
-    if (!isRunning || remainingTimeMs <= 0) {
+    if (isStopwatch || !isRunning || remainingTimeMs <= 0) {
       timerStartTimeRef.current = null;
       return;
     }
@@ -242,46 +336,76 @@ export const useTaskTimer = ({
       
       if (newRemainingMs === 0) {
         timerStartTimeRef.current = null;
-        setIsRunning(false);
-        stopTrayTimer();
-        onTimerEnd?.();
       }
     }, 10);
 
-    // Rust 트레이 타이머와 초 단위 동기화 (1초마다)
-    // Rust 타이머가 실제 시간의 소스이므로 정기적으로 동기화하여 정확도 보장
-    const syncInterval = setInterval(async () => {
-      try {
-        const [remainingSecs, running] = await getRemainingTime();
-        if (running) {
-          // Rust 타이머가 실행 중이면 그 시간으로 동기화
-          const currentSecs = Math.floor(remainingTimeMs / 1000);
-          const rustSecs = remainingSecs;
-          
-          // 차이가 1초 이상이면 Rust 타이머 시간으로 동기화
-          if (Math.abs(currentSecs - rustSecs) > 0) {
-            const rustRemainingMs = rustSecs * 1000;
-            timerStartTimeRef.current = Date.now();
-            timerStartRemainingMsRef.current = rustRemainingMs;
-            setRemainingTimeMs(rustRemainingMs);
-          }
-        } else {
-          // Rust 타이머가 멈춰있으면 로컬 타이머도 멈춤
-          timerStartTimeRef.current = null;
+    return () => {
+      clearInterval(localTimer);
+    };
+  }, [taskId, isRunning, remainingTimeMs, isStopwatch]);
+
+  // Rust 타이머 tick 이벤트 수신 (1초마다)
+  // Rust 타이머가 실제 시간의 소스이므로 모든 창이 같은 시간을 표시하도록 동기화
+  useEffect(() => {
+    if (!taskId) {
+      return;
+    }
+    let unlistenFn: (() => void) | null = null;
+
+    listen<TimerTickPayload>("timer-tick", (event) => {
+      const { taskId: tickTaskId, mode, phase: tickPhase, remainingSecs, elapsedSecs, running } = event.payload;
+      if (tickTaskId !== taskId) {
+        return;
+      }
+      setPhase(tickPhase);
+
+      // 스톱워치는 경과 시간을 Rust 타이머 기준으로 맞춤
+      if (mode === "stopwatch") {
+        const rustElapsedMs = elapsedSecs * 1000;
+        if (!running) {
+          elapsedStartTimeRef.current = null;
           setIsRunning(false);
-          setRemainingTimeMs(Math.max(0, remainingSecs * 1000));
+          setElapsedTimeMs(rustElapsedMs);
+          return;
         }
-      } catch (error) {
-        console.error("[useTaskTimer] Failed to sync with Rust timer:", error);
-        // 에러 발생 시에도 로컬 타이머는 계속 실행
+        const localElapsedMs = elapsedStartTimeRef.current === null
+          ? null
+          : elapsedStartMsRef.current + (Date.now() - elapsedStartTimeRef.current);
+        if (localElapsedMs === null || Math.abs(localElapsedMs - rustElapsedMs) > 1000) {
+          elapsedStartTimeRef.current = Date.now();
+          elapsedStartMsRef.current = rustElapsedMs;
+          setElapsedTimeMs(rustElapsedMs);
+        }
+        return;
       }
-    }, 1000);
+
+      const rustRemainingMs = remainingSecs * 1000;
+
+      if (!running) {
+        // Rust 타이머가 멈췄으면 로컬 타이머도 멈춤
+        timerStartTimeRef.current = null;
+        setIsRunning(false);
+        setRemainingTimeMs(Math.max(0, rustRemainingMs));
+        return;
+      }
+
+      // 로컬 타이머와 1초 넘게 차이 나면 Rust 타이머 시간으로 동기화
+      const localRemainingMs = timerStartTimeRef.current === null
+        ? null
+        : timerStartRemainingMsRef.current - (Date.now() - timerStartTimeRef.current);
+      if (localRemainingMs === null || Math.abs(localRemainingMs - rustRemainingMs) > 1000) {
+        timerStartTimeRef.current = Date.now();
+        timerStartRemainingMsRef.current = rustRemainingMs;
+        setRemainingTimeMs(rustRemainingMs);
+      }
+    }).then((unlisten) => {
+      unlistenFn = unlisten;
+    });
 
     return () => {
-      clearInterval(localTimer);
-      clearInterval(syncInterval);
+      unlistenFn?.();
     };
-  }, [isRunning, remainingTimeMs, onTimerEnd]);
+  }, [taskId]);
 
   // isInProgress가 변경될 때 Rust 트레이 타이머와 동기화
   const prevIsInProgressRef = useRef(isInProgress);
@@ -292,10 +416,27 @@ export const useTaskTimer = ({
     }
     
     // 실행 중(true)으로 변경된 경우에만 타이머 시작/동기화 로직 실행
-    if (isInProgress && !prevIsInProgressRef.current) {
+    if (isInProgress && !prevIsInProgressRef.current && isStopwatch) {
+      // 스톱워치: Rust 스톱워치가 실행 중이면 경과 시간을 맞추고, 아니면 이어서 시작
+      const syncWithRustStopwatch = async () => {
+        try {
+          const [elapsedSecs, running] = await getElapsedTime(taskId);
+          const startMs = running ? elapsedSecs * 1000 : elapsedTimeMs;
+          elapsedStartTimeRef.current = Date.now();
+          elapsedStartMsRef.current = startMs;
+          setElapsedTimeMs(startMs);
+          if (!running) {
+            await startTrayStopwatch(taskId ?? "", taskTitle || "", Math.floor(startMs / 1000));
+          }
+        } catch (error) {
+          console.error("[useTaskTimer] Failed to sync with Rust stopwatch on start:", error);
+        }
+      };
+      syncWithRustStopwatch();
+    } else if (isInProgress && !prevIsInProgressRef.current) {
       const syncWithRustTimer = async () => {
         try {
-          const [remainingSecs, running] = await getRemainingTime();
+          const [remainingSecs, running] = await getRemainingTime(taskId);
           if (running) {
             // Rust 타이머가 이미 실행 중이면 그 시간으로 동기화
             const remainingMs = remainingSecs * 1000;
@@ -304,14 +445,14 @@ export const useTaskTimer = ({
             setRemainingTimeMs(remainingMs);
           } else {
             // Rust 타이머가 멈춰있으면 새로 시작
-            const currentMs = remainingTimeMs > 0 ? remainingTimeMs : (defaultDuration ? defaultDuration * 1000 : expectedDuration * 60 * 1000);
+            const currentMs = remainingTimeMs > 0 ? remainingTimeMs : (defaultDuration ? defaultDuration * 1000 : expectedMinutes * 60 * 1000);
             const startSecs = Math.ceil(currentMs / 1000);
             
             timerStartTimeRef.current = Date.now();
             timerStartRemainingMsRef.current = currentMs;
             setRemainingTimeMs(currentMs);
             
-            await startTrayTimer(startSecs, taskTitle || "");
+            await startTrayTimer(taskId ?? "", startSecs, taskTitle || "");
           }
         } catch (error) {
           console.error("[useTaskTimer] Failed to sync with Rust timer on start:", error);
@@ -330,7 +471,7 @@ export const useTaskTimer = ({
     }
 
     prevIsInProgressRef.current = isInProgress;
-  }, [isInProgress, expectedDuration, defaultDuration, taskTitle]); // isRunning 제거하여 중복 호출 방지
+  }, [taskId, isInProgress, expectedMinutes, defaultDuration, taskTitle, isStopwatch]); // isRunning 제거하여 중복 호출 방지
 
   const handlePlay = useCallback(
     async (e: React.MouseEvent) => {
@@ -340,8 +481,22 @@ export const useTaskTimer = ({
       if (isRunning) {
         return;
       }
+
+      // 스톱워치는 지금까지의 경과 시간에 이어서 측정
+      if (isStopwatch) {
+        elapsedStartTimeRef.current = Date.now();
+        elapsedStartMsRef.current = elapsedTimeMs;
+        setIsRunning(true);
+        try {
+          await startTrayStopwatch(taskId ?? "", taskTitle || "", Math.floor(elapsedTimeMs / 1000));
+        } catch (error) {
+          console.error("[useTaskTimer] Failed to start tray stopwatch:", error);
+        }
+        onStatusChange?.(TaskStatus.IN_PROGRESS);
+        return;
+      }
       
-      const currentTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedDuration * 60 * 1000;
+      const currentTimerDurationMs = defaultDuration ? defaultDuration * 1000 : expectedMinutes * 60 * 1000;
       
       // 재시작 시 남은 시간 우선순위: DB 저장값 > pausedRemainingMsRef > 상태값 > 전체시간
       const pausedMs = pausedRemainingMsRef.current;
@@ -371,9 +526,9 @@ export const useTaskTimer = ({
       // Rust 트레이 타이머 시작 (반드시 완료되도록 보장)
       const remainingSecs = Math.ceil(newRemainingMs / 1000);
       try {
-        await startTrayTimer(remainingSecs, taskTitle || "");
+        await startTrayTimer(taskId ?? "", remainingSecs, taskTitle || "");
         // 타이머 시작 후 Rust 타이머와 동기화하여 정확한 시간 확인
-        const [actualRemainingSecs, isActuallyRunning] = await getRemainingTime();
+        const [actualRemainingSecs, isActuallyRunning] = await getRemainingTime(taskId);
         if (isActuallyRunning) {
           // Rust 타이머가 실제로 실행 중이면 그 시간으로 동기화
           const actualRemainingMs = actualRemainingSecs * 1000;
@@ -383,7 +538,7 @@ export const useTaskTimer = ({
         } else {
           // Rust 타이머가 실행되지 않았으면 다시 시작 시도
           console.warn("[useTaskTimer] Timer not running after start, retrying...");
-          await startTrayTimer(remainingSecs, taskTitle || "");
+          await startTrayTimer(taskId ?? "", remainingSecs, taskTitle || "");
         }
       } catch (error) {
         console.error("[useTaskTimer] Failed to start tray timer:", error);
@@ -393,13 +548,24 @@ export const useTaskTimer = ({
       // 상태 변경 핸들러 호출 (DB 업데이트)
       onStatusChange?.(TaskStatus.IN_PROGRESS);
     },
-    [onStatusChange, remainingTimeMs, savedRemainingTimeSeconds, expectedDuration, defaultDuration, taskTitle, isRunning]
+    [taskId, onStatusChange, remainingTimeMs, savedRemainingTimeSeconds, expectedMinutes, defaultDuration, taskTitle, isRunning, isStopwatch, elapsedTimeMs]
   );
 
   const handlePause = useCallback(
     async (e: React.MouseEvent) => {
       e.stopPropagation();
       setIsRunning(false);
+
+      // 스톱워치는 남은 시간이 없음 (경과 시간은 Rust 타이머가 실행 기록에 저장)
+      if (isStopwatch) {
+        if (elapsedStartTimeRef.current !== null) {
+          setElapsedTimeMs(elapsedStartMsRef.current + (Date.now() - elapsedStartTimeRef.current));
+        }
+        elapsedStartTimeRef.current = null;
+        await stopTrayTimer(taskId);
+        onStatusChange?.(TaskStatus.PAUSED);
+        return;
+      }
       
       // 로컬 타이머 기준 남은 시간 계산
       let localRemainingMs = remainingTimeMs;
@@ -412,7 +578,7 @@ export const useTaskTimer = ({
       timerStartTimeRef.current = null;
       
       // Rust 타이머 정지 및 남은 시간 조회
-      const remainingSecs = await stopTrayTimer();
+      const remainingSecs = await stopTrayTimer(taskId);
       const rustRemainingMs = remainingSecs * 1000;
       
       // 로컬 타이머와 Rust 타이머 중 더 정확한 값 사용 (더 작은 값 = 더 많이 경과된 값)
@@ -425,7 +591,7 @@ export const useTaskTimer = ({
       const finalRemainingSecs = Math.ceil(finalMs / 1000);
       onStatusChange?.(TaskStatus.PAUSED, { remainingTimeSeconds: finalRemainingSecs });
     },
-    [onStatusChange, remainingTimeMs]
+    [taskId, onStatusChange, remainingTimeMs, isStopwatch]
   );
 
   const handleQuickExtendTime = useCallback(
@@ -434,8 +600,8 @@ export const useTaskTimer = ({
       const extension: TimeExtensionHistory = {
         id: crypto.randomUUID(),
         addedMinutes: minutes,
-        previousDuration: expectedDuration,
-        newDuration: expectedDuration + minutes,
+        previousDuration: expectedMinutes,
+        newDuration: expectedMinutes + minutes,
         createdAt: new Date(),
       };
       onExtendTime?.(extension);
@@ -446,10 +612,10 @@ export const useTaskTimer = ({
       
       // Rust 트레이 타이머 동기화
       if (isRunning) {
-        await syncTrayTimer(Math.ceil(newRemainingMs / 1000));
+        await syncTrayTimer(taskId, Math.ceil(newRemainingMs / 1000));
       }
     },
-    [expectedDuration, onExtendTime, isRunning, remainingTimeMs]
+    [taskId, expectedMinutes, onExtendTime, isRunning, remainingTimeMs]
   );
 
   const extendRemainingTime = useCallback(async (minutes: number) => {
@@ -458,13 +624,17 @@ export const useTaskTimer = ({
     setRemainingTimeMs(newRemainingMs);
     
     if (isRunning) {
-      await syncTrayTimer(Math.ceil(newRemainingMs / 1000));
+      await syncTrayTimer(taskId, Math.ceil(newRemainingMs / 1000));
     }
-  }, [isRunning, remainingTimeMs]);
+  }, [taskId, isRunning, remainingTimeMs]);
 
   return {
     remainingTimeMs,
     remainingTimeSeconds,
+    isStopwatch,
+    elapsedTimeMs,
+    displayTimeMs: isStopwatch ? elapsedTimeMs : remainingTimeMs,
+    phase,
     isRunning,
     progress,
     completedProgress,
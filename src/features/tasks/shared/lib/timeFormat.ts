@@ -59,11 +59,15 @@ export const formatSecondsReadable = (totalSeconds: number): string => {
  * 소비된 시간과 전체 시간을 "소비시간/전체시간" 형식으로 표시
  * - 소비된 시간이 0이면 전체 시간만 표시
  * - 전체 시간은 분 단위(expectedDuration), 남은 시간은 초 단위(remainingTimeSeconds)
+ * - 예상 시간이 없는 task(스톱워치)는 "예상 시간 없음"
  */
 export const formatDurationWithSpent = (
-  expectedDurationMinutes: number,
+  expectedDurationMinutes: number | null | undefined,
   remainingTimeSeconds: number | null | undefined
 ): string => {
+  if (expectedDurationMinutes === null || expectedDurationMinutes === undefined) {
+    return "예상 시간 없음";
+  }
   const totalSeconds = expectedDurationMinutes * 60;
   const totalTimeText = formatSecondsReadable(totalSeconds);
   
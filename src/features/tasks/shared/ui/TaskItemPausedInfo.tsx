@@ -5,7 +5,10 @@ import { Clock } from "lucide-react";
 interface TaskItemPausedInfoProps {
   lastPausedAt?: Date | string;
   remainingTimeSeconds: number;
-  expectedDurationMinutes: number;
+  /** 예상 시간 (분) - 없으면 스톱워치로 측정한 누적 시간만 표시 */
+  expectedDurationMinutes: number | null;
+  /** 누적 진행 시간 (분) - 스톱워치 task의 진행 시간 */
+  totalTimeSpentMinutes?: number;
   delayDays: number;
   tags?: string[];
   isHovered: boolean;
@@ -19,15 +22,18 @@ export const TaskItemPausedInfo = ({
   lastPausedAt,
   remainingTimeSeconds,
   expectedDurationMinutes,
+  totalTimeSpentMinutes = 0,
   delayDays: _delayDays,
   tags,
   isHovered,
 }: TaskItemPausedInfoProps) => {
-  const totalSeconds = expectedDurationMinutes * 60;
-  const spentSeconds = Math.max(0, totalSeconds - remainingTimeSeconds);
+  const totalSeconds = expectedDurationMinutes === null ? null : expectedDurationMinutes * 60;
+  const spentSeconds = totalSeconds === null
+    ? totalTimeSpentMinutes * 60
+    : Math.max(0, totalSeconds - remainingTimeSeconds);
   
   const spentTimeText = formatSecondsReadable(spentSeconds);
-  const totalTimeText = formatSecondsReadable(totalSeconds);
+  const totalTimeText = totalSeconds === null ? null : formatSecondsReadable(totalSeconds);
 
   return (
     <motion.div
@@ -45,7 +51,11 @@ export const TaskItemPausedInfo = ({
         </>
       )}
       <Clock className="w-3 h-3 text-gray-500 mr-1" />
-      <span>진행 {spentTimeText} / 전체 {totalTimeText}</span>
+      {totalTimeText === null ? (
+        <span>진행 {spentTimeText}</span>
+      ) : (
+        <span>진행 {spentTimeText} / 전체 {totalTimeText}</span>
+      )}
       {/* {isDelayed && (
         <>
           <span className="mx-1.5 text-gray-600">•</span>
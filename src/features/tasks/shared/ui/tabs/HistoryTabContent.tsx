@@ -68,6 +68,8 @@ const getActionInfo = (actionType: string) => {
       return { icon: RotateCcw, color: "text-cyan-400", bg: "bg-cyan-500/20", label: "복원" };
     case "TARGET_DATE_CHANGED":
       return { icon: Calendar, color: "text-orange-400", bg: "bg-orange-500/20", label: "일정 변경" };
+    case "TIMER_ENDED":
+      return { icon: Clock, color: "text-red-400", bg: "bg-red-500/20", label: "시간 종료" };
     default:
       return { icon: Clock, color: "text-gray-400", bg: "bg-gray-500/20", label: "변경" };
   }
@@ -150,6 +152,7 @@ export const HistoryTabContent = ({ sortedHistory, sortedActionHistory }: Histor
                     {formatDateTime(run.startedAt)}
                   </div>
                   <div className="text-[10px] text-gray-500">
+                    {run.phase !== "focus" && "휴식 · "}
                     {run.endType === "completed" ? "완료" :
                      run.endType === "paused" ? "일시정지" :
                      run.endType === "timeout" ? "시간 초과" : "중단됨"}
@@ -161,7 +164,9 @@ export const HistoryTabContent = ({ sortedHistory, sortedActionHistory }: Histor
                   {formatMinutes(Math.floor(run.duration / 60))}
                 </div>
                 <div className="text-[10px] text-gray-500">
-                  소요 시간
+                  {run.overtimeSeconds > 0
+                    ? `소요 시간 · +${formatMinutes(Math.floor(run.overtimeSeconds / 60))} 초과`
+                    : "소요 시간"}
                 </div>
               </div>
             </div>
@@ -276,22 +276,28 @@ export const MainPage = () => {
           return currentRunAt > latestRunAt ? current : latest;
         }, otherInProgressTasks[0]);
 
-        // 마지막 실행된 task의 남은 시간 계산
-        const remainingSecs = lastRunTask.remainingTimeSeconds ?? 
-          (lastRunTask.expectedDuration ?? 5) * 60;
-        
-        // 트레이 업데이트
-        const { updateTrayTimer } = await import("@shared/lib/tray");
-        await updateTrayTimer(remainingSecs, lastRunTask.title);
-        console.log("[handleStatusChange] Updated tray to last running task:", {
-          taskId: lastRunTask.id,
-          title: lastRunTask.title,
-          remainingSecs
-        });
+        // 예상 시간이 없는 task는 스톱워치로 트레이 표시 전환 (실행 중이면 경과 시간 유지)
+        if (lastRunTask.expectedDuration == null) {
+          const { startTrayStopwatch } = await import("@shared/lib/tray");
+          await startTrayStopwatch(lastRunTask.id, lastRunTask.title);
+        } else {
+          // 마지막 실행된 task의 남은 시간 계산
+          const remainingSecs = lastRunTask.remainingTimeSeconds ?? 
+            lastRunTask.expectedDuration * 60;
+          
+          // 트레이 업데이트
+          const { updateTrayTimer } = await import("@shared/lib/tray");
+          await updateTrayTimer(lastRunTask.id, remainingSecs, lastRunTask.title);
+          console.log("[handleStatusChange] Updated tray to last running task:", {
+            taskId: lastRunTask.id,
+            title: lastRunTask.title,
+            remainingSecs
+          });
+        }
       } else {
         // 실행 중인 task가 없으면 트레이를 "미루미"로 변경
         const { stopTrayTimer } = await import("@shared/lib/tray");
-        await stopTrayTimer(true);
+        await stopTrayTimer(undefined, true);
       }
     }
   }, [tasks, updateTask]);
@@ -449,7 +455,7 @@ export const MainPage = () => {
     // 트레이 타이머 중지
     try {
       const { stopTrayTimer } = await import("@shared/lib/tray");
-      await stopTrayTimer(true);
+      await stopTrayTimer(undefined, true);
     } catch (error) {
       console.error("[MainPage] Failed to stop tray timer after pausing all tasks:", error);
     }
@@ -3,15 +3,18 @@ import { open, save } from "@tauri-apps/plugin-dialog";
 import { join, downloadDir } from "@tauri-apps/api/path";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { useDbStatus } from "@shared/hooks";
+import { getErrorMessage } from "@shared/lib/error";
 
 interface OnboardingPageProps {
   onComplete: () => void;
 }
 
 export const OnboardingPage = ({ onComplete }: OnboardingPageProps) => {
-  const { initDb, loadExistingDb, error } = useDbStatus();
-  const [busyAction, setBusyAction] = useState<"init" | "load" | null>(null);
+  const { status, initDb, loadExistingDb, error } = useDbStatus();
+  const [busyAction, setBusyAction] = useState<"init" | "load" | "unlock" | null>(null);
   const [localError, setLocalError] = useState<string | null>(null);
+  // 암호화된 DB의 암호 (비워두면 평문 DB)
+  const [passphrase, setPassphrase] = useState("");
 
   const appWindow = getCurrentWindow();
 
@@ -53,11 +56,11 @@ export const OnboardingPage = ({ onComplete }: OnboardingPageProps) => {
         if (!filePath.endsWith(".db") && !filePath.endsWith(".sqlite") && !filePath.endsWith(".sqlite3")) {
           filePath = filePath + ".db";
         }
-        await initDb(filePath);
+        await initDb(filePath, passphrase || undefined);
         onComplete();
       }
     } catch (e) {
-      setLocalError(String(e));
+      setLocalError(getErrorMessage(e));
     } finally {
       setBusyAction(null);
     }
@@ -78,11 +81,26 @@ export const OnboardingPage = ({ onComplete }: OnboardingPageProps) => {
       });
 
       if (selected) {
-        await loadExistingDb(selected as string);
+        await loadExistingDb(selected as string, { passphrase: passphrase || undefined });
         onComplete();
       }
     } catch (e) {
-      setLocalError(String(e));
+      setLocalError(getErrorMessage(e));
+    } finally {
+      setBusyAction(null);
+    }
+  };
+
+  // 설정된 암호화 DB 잠금 해제 (앱 재시작 후)
+  const handleUnlock = async () => {
+    if (!status?.path) return;
+    setBusyAction("unlock");
+    setLocalError(null);
+    try {
+      await loadExistingDb(status.path, { passphrase });
+      onComplete();
+    } catch (e) {
+      setLocalError(getErrorMessage(e));
     } finally {
       setBusyAction(null);
     }
@@ -164,8 +182,31 @@ export const OnboardingPage = ({ onComplete }: OnboardingPageProps) => {
               </div>
             )}
 
+            {/* 암호 (암호화된 DB) */}
+            <div className="mb-6">
+              <input
+                type="password"
+                value={passphrase}
+                onChange={(e) => setPassphrase(e.target.value)}
+                placeholder="암호 (선택, 입력하면 암호화된 DB로 생성)"
+                className="w-full px-4 py-3 bg-slate-900/50 text-white text-sm rounded-xl border border-slate-700 focus:border-amber-500 focus:outline-none placeholder:text-slate-500"
+              />
+            </div>
+
             {/* 버튼들 */}
             <div className="space-y-4">
+              {/* 암호화된 DB 잠금 해제 */}
+              {status?.needsPassphrase && (
+                <button
+                  onClick={handleUnlock}
+                  disabled={busyAction !== null || !passphrase}
+                  className="w-full px-6 py-4 bg-gradient-to-r from-amber-500 to-orange-500 hover:from-amber-400 hover:to-orange-400 text-white font-medium rounded-xl transition-all duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+                  title={status.path}
+                >
+                  {busyAction === "unlock" ? "잠금 해제 중..." : "암호화된 DB 잠금 해제"}
+                </button>
+              )}
+
               {/* 새 DB 생성 (경로 지정) */}
               <button
                 onClick={handleCreateNew}
@@ -5,6 +5,7 @@ import { useDbStatus, useSettings, useTableViewer, useSidebarCounts, useAppVersi
 import { AppLayout } from "@widgets/index";
 import { SettingsSelect, SettingsInput, SettingsToggle } from "@shared/ui";
 import { type SidebarMenuId } from "@widgets/layout/Sidebar";
+import { getErrorMessage } from "@shared/lib/error";
 
 interface SettingsPageProps {
   onBack: (menuId?: SidebarMenuId) => void;
@@ -24,6 +25,8 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
     setNotificationSound,
     notificationVibration,
     setNotificationVibration,
+    timerCycleEnabled,
+    setTimerCycleEnabled,
     loading: settingsLoading,
   } = useSettings();
   const { tables, selectedTable, rows, selectTable, loading: tableLoading } = useTableViewer();
@@ -41,9 +44,9 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
     }
   };
 
-  // 다른 DB 파일로 변경
-  const handleChangeDb = async () => {
-    setBusyAction("change");
+  // 다른 DB 파일로 변경 (readOnly면 파일을 바꾸지 않고 열람만)
+  const handleChangeDb = async (readOnly = false) => {
+    setBusyAction(readOnly ? "readOnly" : "change");
     setError(null);
     try {
       const selected = await open({
@@ -56,11 +59,11 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
       });
 
       if (selected) {
-        await loadExistingDb(selected as string);
+        await loadExistingDb(selected as string, { readOnly });
         onDbChange();
       }
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
     } finally {
       setBusyAction(null);
     }
@@ -74,7 +77,7 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
       await logout();
       onDbChange();
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
     } finally {
       setBusyAction(null);
     }
@@ -127,6 +130,11 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
                     </p>
                   </div>
                   <div className="flex items-center gap-2 flex-shrink-0">
+                    {status?.readOnly && (
+                      <span className="px-2 py-1 bg-yellow-500/20 text-yellow-400 text-xs rounded-full">
+                        읽기 전용
+                      </span>
+                    )}
                     {status?.exists ? (
                       <span className="px-2 py-1 bg-green-500/20 text-green-400 text-xs rounded-full">
                         연결됨
@@ -142,12 +150,19 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
                 {/* DB 버튼들 */}
                 <div className="flex gap-3">
                   <button
-                    onClick={handleChangeDb}
+                    onClick={() => handleChangeDb()}
                     disabled={busyAction !== null}
                     className="flex-1 px-4 py-2.5 bg-white/5 hover:bg-white/10 text-gray-300 text-sm font-medium rounded-lg border border-white/5 transition-colors disabled:opacity-50"
                   >
                     {busyAction === "change" ? "로딩중..." : "다른 DB 파일 선택"}
                   </button>
+                  <button
+                    onClick={() => handleChangeDb(true)}
+                    disabled={busyAction !== null}
+                    className="px-4 py-2.5 bg-white/5 hover:bg-white/10 text-gray-300 text-sm font-medium rounded-lg border border-white/5 transition-colors disabled:opacity-50"
+                  >
+                    {busyAction === "readOnly" ? "로딩중..." : "읽기 전용으로 열기"}
+                  </button>
                   <button
                     onClick={handleLogout}
                     disabled={busyAction !== null}
@@ -219,6 +234,19 @@ export const SettingsPage = ({ onBack, onDbChange }: SettingsPageProps) => {
                   />
                 </div>
 
+                {/* 사이클 모드 */}
+                <div className="px-5 py-4 flex items-center justify-between gap-4">
+                  <div className="flex-1">
+                    <p className="text-white text-sm font-medium">뽀모도로 사이클</p>
+                    <p className="text-gray-500 text-xs mt-0.5">집중과 휴식 구간을 자동으로 전환</p>
+                  </div>
+                  <SettingsToggle
+                    checked={timerCycleEnabled}
+                    onChange={setTimerCycleEnabled}
+                    disabled={settingsLoading}
+                  />
+                </div>
+
                 {/* 알림 소리 */}
                 <div className="px-5 py-4 flex items-center justify-between gap-4">
                   <div className="flex-1">
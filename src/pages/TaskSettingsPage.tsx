@@ -63,7 +63,7 @@ export const TaskSettingsPage = ({ task, onClose }: TaskSettingsPageProps) => {
 
   // 예상 시간 텍스트
   const expectedDurationText = formatDurationWithSpent(
-    task.expectedDuration ?? 5,
+    task.expectedDuration,
     task.remainingTimeSeconds
   );
 
@@ -97,7 +97,8 @@ export const TaskSettingsPage = ({ task, onClose }: TaskSettingsPageProps) => {
     e.preventDefault();
     if (timeExtendMinutes === 0) return;
     
-    const currentDuration = task.expectedDuration ?? 5;
+    // 예상 시간이 없던 task는 연장한 시간이 새 예상 시간이 됨
+    const currentDuration = task.expectedDuration ?? 0;
     const newDuration = Math.max(1, currentDuration + timeExtendMinutes);
     
     await extendTime({
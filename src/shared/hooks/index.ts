@@ -1,7 +1,9 @@
-export { useDbStatus, type DbStatus } from "./useDbStatus";
+export { useDbStatus, type DbStatus, type DbStats, type CompactReport, type DbCheckReport, type DbIssue, type DbIssueKind } from "./useDbStatus";
 export { useTasks, type UseTasksResult } from "./useTasks";
 export { useSettings, type UseSettingsResult } from "./useSettings";
 export { useAppVersion } from "./useAppVersion";
 export { useTableViewer, type UseTableViewerResult } from "./useTableViewer";
 export { useSidebarCounts, type SidebarCounts } from "./useSidebarCounts";
 
+export { useBackups, type BackupInfo, type BackupReason, type UseBackupsResult } from "./useBackups";
+export { useWorkspaces, type Workspace, type UseWorkspacesResult } from "./useWorkspaces";
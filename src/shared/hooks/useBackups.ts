@@ -0,0 +1,71 @@
+import { useState, useCallback, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { getErrorMessage } from "@shared/lib/error";
+import type { DbStatus } from "./useDbStatus";
+
+export type BackupReason = "pre-migration" | "scheduled" | "pre-restore" | "pre-repair";
+
+export interface BackupInfo {
+  fileName: string;
+  path: string;
+  reason: BackupReason;
+  /** 생성 시각 (UTC, "YYYY-MM-DD HH:mm:ss") */
+  createdAt: string;
+  sizeBytes: number;
+}
+
+export interface UseBackupsResult {
+  backups: BackupInfo[];
+  loading: boolean;
+  error: string | null;
+  refresh: () => Promise<void>;
+  restoreBackup: (fileName: string) => Promise<DbStatus>;
+}
+
+export function useBackups(): UseBackupsResult {
+  const [backups, setBackups] = useState<BackupInfo[]>([]);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const refresh = useCallback(async () => {
+    setLoading(true);
+    setError(null);
+    try {
+      const result = await invoke<BackupInfo[]>("list_backups");
+      setBackups(result);
+    } catch (e) {
+      setError(getErrorMessage(e));
+    } finally {
+      setLoading(false);
+    }
+  }, []);
+
+  const restoreBackup = useCallback(async (fileName: string) => {
+    setLoading(true);
+    setError(null);
+    try {
+      const status = await invoke<DbStatus>("restore_backup", { fileName });
+      // 복원 직전 백업(pre-restore)이 추가되므로 목록 갱신
+      const result = await invoke<BackupInfo[]>("list_backups");
+      setBackups(result);
+      return status;
+    } catch (e) {
+      setError(getErrorMessage(e));
+      throw e;
+    } finally {
+      setLoading(false);
+    }
+  }, []);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  return {
+    backups,
+    loading,
+    error,
+    refresh,
+    restoreBackup,
+  };
+}
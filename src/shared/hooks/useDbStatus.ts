@@ -1,5 +1,7 @@
 import { useState, useCallback, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { getErrorCode, getErrorMessage, type AppErrorCode } from "@shared/lib/error";
 
 export interface DbStatus {
   configured: boolean;
@@ -7,50 +9,159 @@ export interface DbStatus {
   exists: boolean;
   sizeBytes?: number;
   tables: string[];
+  /** 파일이 있을 때만 조회 */
+  stats: DbStats | null;
+  /** 마지막 백업 시각 (UTC, "YYYY-MM-DD HH:mm:ss") */
+  lastBackupAt: string | null;
+  /** 읽기 전용으로 열림 (readOnly 옵션으로 열었거나 다른 인스턴스가 사용 중, 수정 시 DB_READ_ONLY) */
+  readOnly: boolean;
+  /** 다른 인스턴스가 보유한 잠금 */
+  lockHolder: DbLockInfo | null;
+  /** 잠금의 하트비트가 끊김 (loadExistingDb의 takeOver로 가져올 수 있음) */
+  lockStale: boolean;
+  /** 암호화된 DB (SQLCipher) */
+  encrypted: boolean;
+  /** 암호 입력 전 (loadExistingDb에 passphrase를 넘겨 잠금 해제) */
+  needsPassphrase: boolean;
+}
+
+/** DB 잠금 파일 ({db}.lock) 내용 */
+export interface DbLockInfo {
+  host: string;
+  pid: number;
+  instanceId: string;
+  /** UTC, "YYYY-MM-DD HH:mm:ss" */
+  acquiredAt: string;
+  heartbeatAt: string;
+}
+
+export interface DbStats {
+  tableStats: { name: string; rowCount: number }[];
+  pageSize: number;
+  pageCount: number;
+  /** 사용하지 않는 페이지 수 (compact_db로 반환 가능) */
+  freelistCount: number;
+  walSizeBytes: number;
+}
+
+/** compact_db 결과 (DB + WAL 파일 크기) */
+export interface CompactReport {
+  sizeBefore: number;
+  sizeAfter: number;
+  reclaimedBytes: number;
+}
+
+export type DbIssueKind =
+  | "FOREIGN_KEY"
+  | "ORPHAN_ROW"
+  | "NEGATIVE_VALUE"
+  | "INVALID_STATUS"
+  | "INVALID_PRIORITY";
+
+export interface DbIssue {
+  kind: DbIssueKind;
+  table: string;
+  rowId: string;
+  column: string | null;
+  value: string | null;
+  repairable: boolean;
+}
+
+/** check_db 결과 (repair 모드면 복구 후 다시 점검한 결과) */
+export interface DbCheckReport {
+  ok: boolean;
+  integrityErrors: string[];
+  issues: DbIssue[];
+  repairedRows: number;
+  backupFile: string | null;
 }
 
 export function useDbStatus() {
   const [status, setStatus] = useState<DbStatus | null>(null);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  /** 마지막 에러 코드 (온보딩/복구 화면 분기용) */
+  const [errorCode, setErrorCode] = useState<AppErrorCode | null>(null);
 
   const refresh = useCallback(async () => {
     setLoading(true);
     setError(null);
+    setErrorCode(null);
     try {
       const result = await invoke<DbStatus>("get_db_status");
       setStatus(result);
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
     } finally {
       setLoading(false);
     }
   }, []);
 
-  const initDb = useCallback(async (path?: string) => {
+  /** 새 DB 생성 (passphrase가 있으면 암호화된 DB로 생성) */
+  const initDb = useCallback(async (path?: string, passphrase?: string) => {
     setLoading(true);
     setError(null);
+    setErrorCode(null);
     try {
-      const result = await invoke<DbStatus>("init_db", { path });
+      const result = await invoke<DbStatus>("init_db", { path, passphrase });
       setStatus(result);
       return result;
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
       throw e;
     } finally {
       setLoading(false);
     }
   }, []);
 
-  const loadExistingDb = useCallback(async (path: string) => {
+  /**
+   * 기존 DB 불러오기
+   * - takeOver: 응답 없는 잠금을 가져옴 (DB_LOCK_STALE 확인 후 사용)
+   * - readOnly: 파일을 바꾸지 않고 열람 (백업, 내보낸 DB 등, 마이그레이션 안 함)
+   * - passphrase: 암호화된 DB의 암호 (없으면 PASSPHRASE_REQUIRED)
+   */
+  const loadExistingDb = useCallback(
+    async (
+      path: string,
+      options?: { takeOver?: boolean; readOnly?: boolean; passphrase?: string },
+    ) => {
+      setLoading(true);
+      setError(null);
+      setErrorCode(null);
+      try {
+        const result = await invoke<DbStatus>("load_existing_db", {
+          path,
+          takeOver: options?.takeOver ?? false,
+          readOnly: options?.readOnly ?? false,
+          passphrase: options?.passphrase,
+        });
+        setStatus(result);
+        return result;
+      } catch (e) {
+        setError(getErrorMessage(e));
+        setErrorCode(getErrorCode(e));
+        throw e;
+      } finally {
+        setLoading(false);
+      }
+    },
+    [],
+  );
+
+  /** DB 파일을 새 경로로 이동 (검증 후 이전 파일 삭제) */
+  const moveDb = useCallback(async (path: string) => {
     setLoading(true);
     setError(null);
+    setErrorCode(null);
     try {
-      const result = await invoke<DbStatus>("load_existing_db", { path });
+      const result = await invoke<DbStatus>("move_db", { path });
       setStatus(result);
       return result;
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
       throw e;
     } finally {
       setLoading(false);
@@ -60,29 +171,138 @@ export function useDbStatus() {
   const logout = useCallback(async () => {
     setLoading(true);
     setError(null);
+    setErrorCode(null);
     try {
       await invoke("logout");
       setStatus(null);
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
       throw e;
     } finally {
       setLoading(false);
     }
   }, []);
 
+  const checkDb = useCallback(async (repair = false) => {
+    setError(null);
+    setErrorCode(null);
+    try {
+      return await invoke<DbCheckReport>("check_db", { repair });
+    } catch (e) {
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
+      throw e;
+    }
+  }, []);
+
+  const compactDb = useCallback(async () => {
+    setError(null);
+    setErrorCode(null);
+    try {
+      const report = await invoke<CompactReport>("compact_db");
+      const result = await invoke<DbStatus>("get_db_status");
+      setStatus(result);
+      return report;
+    } catch (e) {
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
+      throw e;
+    }
+  }, []);
+
+  /** 현재 DB 암호화 (암호화된 사본으로 교체) */
+  const encryptDb = useCallback(async (passphrase: string) => {
+    setError(null);
+    setErrorCode(null);
+    try {
+      const result = await invoke<DbStatus>("encrypt_db", { passphrase });
+      setStatus(result);
+      return result;
+    } catch (e) {
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
+      throw e;
+    }
+  }, []);
+
+  /** DB 암호 변경 (현재 암호가 틀리면 WRONG_PASSPHRASE) */
+  const changePassphrase = useCallback(async (current: string, passphrase: string) => {
+    setError(null);
+    setErrorCode(null);
+    try {
+      const result = await invoke<DbStatus>("change_passphrase", { current, passphrase });
+      setStatus(result);
+      return result;
+    } catch (e) {
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
+      throw e;
+    }
+  }, []);
+
+  /** 현재 DB를 평문 사본으로 내보내기 (현재 DB는 암호화 유지) */
+  const exportDecryptedDb = useCallback(async (path: string) => {
+    setError(null);
+    setErrorCode(null);
+    try {
+      await invoke("export_decrypted_db", { path });
+    } catch (e) {
+      setError(getErrorMessage(e));
+      setErrorCode(getErrorCode(e));
+      throw e;
+    }
+  }, []);
+
   useEffect(() => {
     refresh();
   }, [refresh]);
 
+  // 워크스페이스 전환 (트레이 등) 시 상태 갱신
+  useEffect(() => {
+    let unlistenFn: (() => void) | null = null;
+    listen<DbStatus>("workspace-changed", (event) => {
+      setStatus(event.payload);
+    }).then((unlisten) => {
+      unlistenFn = unlisten;
+    });
+
+    return () => {
+      unlistenFn?.();
+    };
+  }, []);
+
+  // DB 파일이 외부에서 바뀌거나 (동기화 폴더 등) 잠금을 잃으면 상태 다시 조회
+  useEffect(() => {
+    const unlistenFns: (() => void)[] = [];
+    for (const event of ["db-changed", "db-lock-lost"]) {
+      listen(event, () => {
+        refresh();
+      }).then((unlisten) => {
+        unlistenFns.push(unlisten);
+      });
+    }
+
+    return () => {
+      unlistenFns.forEach((unlisten) => unlisten());
+    };
+  }, [refresh]);
+
   return {
     status,
     loading,
     error,
+    errorCode,
     refresh,
     initDb,
     loadExistingDb,
+    moveDb,
     logout,
+    checkDb,
+    compactDb,
+    encryptDb,
+    changePassphrase,
+    exportDecryptedDb,
   };
 }
 
@@ -1,20 +1,43 @@
 import { useState, useCallback, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { getErrorMessage } from "@shared/lib/error";
 
-export interface Setting {
-  id: string;
+export type SettingScope = "machine" | "workspace";
+
+export type SettingKind =
+  | { type: "bool" }
+  | { type: "int"; min: number; max: number }
+  | { type: "choice"; options: string[] }
+  | { type: "intList"; min: number; max: number };
+
+/** 등록된 설정 (값은 kind에 맞는 JSON 값) */
+export interface SettingEntry {
+  key: string;
+  value: unknown;
+  defaultValue: unknown;
+  kind: SettingKind;
+  /** machine: config.json (기기별), workspace: DB (워크스페이스별) */
+  scope: SettingScope;
+  /** 워크스페이스 설정의 마지막 변경 시각 (UTC, "YYYY-MM-DD HH:mm:ss") */
+  updatedAt: string | null;
+}
+
+/** setting-changed 이벤트 페이로드 (모든 창에 전송) */
+export interface SettingChangedPayload {
   key: string;
-  value: string | null;
-  updatedAt: string;
+  value: unknown;
+  scope: SettingScope;
 }
 
 export interface UseSettingsResult {
-  settings: Setting[];
+  settings: SettingEntry[];
   loading: boolean;
   error: string | null;
   refresh: () => Promise<void>;
-  getSetting: (key: string) => Promise<string | null>;
-  setSetting: (key: string, value: string) => Promise<void>;
+  getSetting: (key: string) => Promise<unknown>;
+  /** 잘못된 키나 값은 INVALID_INPUT */
+  setSetting: (key: string, value: unknown) => Promise<void>;
   // 편의 메서드
   theme: string;
   setTheme: (theme: string) => Promise<void>;
@@ -26,10 +49,12 @@ export interface UseSettingsResult {
   setNotificationSound: (enabled: boolean) => Promise<void>;
   notificationVibration: boolean;
   setNotificationVibration: (enabled: boolean) => Promise<void>;
+  timerCycleEnabled: boolean;
+  setTimerCycleEnabled: (enabled: boolean) => Promise<void>;
 }
 
 export function useSettings(): UseSettingsResult {
-  const [settings, setSettings] = useState<Setting[]>([]);
+  const [settings, setSettings] = useState<SettingEntry[]>([]);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
 
@@ -37,87 +62,96 @@ export function useSettings(): UseSettingsResult {
     setLoading(true);
     setError(null);
     try {
-      const result = await invoke<Setting[]>("get_all_settings");
+      const result = await invoke<SettingEntry[]>("get_all_settings");
       setSettings(result);
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
     } finally {
       setLoading(false);
     }
   }, []);
 
-  const getSetting = useCallback(async (key: string): Promise<string | null> => {
-    const result = await invoke<string | null>("get_setting", { key });
-    return result;
+  const getSetting = useCallback(async (key: string): Promise<unknown> => {
+    return await invoke<unknown>("get_setting", { key });
   }, []);
 
-  const setSetting = useCallback(async (key: string, value: string): Promise<void> => {
+  // 저장 결과는 setting-changed 이벤트로 반영
+  const setSetting = useCallback(async (key: string, value: unknown): Promise<void> => {
     await invoke("set_setting", { key, value });
-    await refresh();
-  }, [refresh]);
-
-  // 설정값 파싱 헬퍼
-  const getSettingValue = useCallback((key: string, defaultValue: string): string => {
-    const setting = settings.find(s => s.key === key);
-    if (!setting?.value) return defaultValue;
-    try {
-      // JSON 문자열인 경우 파싱
-      return JSON.parse(setting.value);
-    } catch {
-      return setting.value;
-    }
-  }, [settings]);
-
-  const getSettingBool = useCallback((key: string, defaultValue: boolean): boolean => {
-    const setting = settings.find(s => s.key === key);
-    if (!setting?.value) return defaultValue;
-    try {
-      return JSON.parse(setting.value);
-    } catch {
-      return defaultValue;
-    }
-  }, [settings]);
+  }, []);
 
-  const getSettingNumber = useCallback((key: string, defaultValue: number): number => {
+  // 설정값 헬퍼 (백엔드가 타입에 맞는 값을 반환, 조회 전에는 기본값)
+  const getSettingValue = useCallback(<T,>(key: string, defaultValue: T): T => {
     const setting = settings.find(s => s.key === key);
-    if (!setting?.value) return defaultValue;
-    try {
-      return Number(JSON.parse(setting.value));
-    } catch {
-      return defaultValue;
-    }
+    return setting === undefined ? defaultValue : (setting.value as T);
   }, [settings]);
 
   // 편의 메서드들
-  const theme = getSettingValue("theme", "system");
+  const theme = getSettingValue<string>("theme", "system");
   const setTheme = useCallback(async (value: string) => {
-    await setSetting("theme", JSON.stringify(value));
+    await setSetting("theme", value);
   }, [setSetting]);
 
-  const language = getSettingValue("language", "ko");
+  const language = getSettingValue<string>("language", "ko");
   const setLanguage = useCallback(async (value: string) => {
-    await setSetting("language", JSON.stringify(value));
+    await setSetting("language", value);
   }, [setSetting]);
 
-  const timerDefaultMinutes = getSettingNumber("timer_default_minutes", 5);
+  const timerDefaultMinutes = getSettingValue<number>("timer_default_minutes", 5);
   const setTimerDefaultMinutes = useCallback(async (value: number) => {
-    await setSetting("timer_default_minutes", String(value));
+    await setSetting("timer_default_minutes", value);
   }, [setSetting]);
 
-  const notificationSound = getSettingBool("notification_sound", true);
+  const notificationSound = getSettingValue<boolean>("notification_sound", true);
   const setNotificationSound = useCallback(async (value: boolean) => {
-    await setSetting("notification_sound", String(value));
+    await setSetting("notification_sound", value);
   }, [setSetting]);
 
-  const notificationVibration = getSettingBool("notification_vibration", true);
+  const notificationVibration = getSettingValue<boolean>("notification_vibration", true);
   const setNotificationVibration = useCallback(async (value: boolean) => {
-    await setSetting("notification_vibration", String(value));
+    await setSetting("notification_vibration", value);
+  }, [setSetting]);
+
+  const timerCycleEnabled = getSettingValue<boolean>("timer_cycle_enabled", false);
+  const setTimerCycleEnabled = useCallback(async (value: boolean) => {
+    await setSetting("timer_cycle_enabled", value);
   }, [setSetting]);
 
   useEffect(() => {
     refresh();
   }, [refresh]);
 
+  // 다른 창(또는 트레이)에서 바꾼 설정 반영
+  useEffect(() => {
+    let unlistenFn: (() => void) | null = null;
+    listen<SettingChangedPayload>("setting-changed", (event) => {
+      const { key, value } = event.payload;
+      setSettings((prev) => prev.map((s) => (s.key === key ? { ...s, value } : s)));
+    }).then((unlisten) => {
+      unlistenFn = unlisten;
+    });
+
+    return () => {
+      unlistenFn?.();
+    };
+  }, []);
+
+  // 워크스페이스 전환이나 외부 변경으로 DB가 바뀌면 워크스페이스 설정 다시 조회
+  useEffect(() => {
+    const unlistenFns: (() => void)[] = [];
+    for (const event of ["workspace-changed", "db-changed"]) {
+      listen(event, () => {
+        refresh();
+      }).then((unlisten) => {
+        unlistenFns.push(unlisten);
+      });
+    }
+
+    return () => {
+      unlistenFns.forEach((unlisten) => unlisten());
+    };
+  }, [refresh]);
+
   return {
     settings,
     loading,
@@ -135,6 +169,8 @@ export function useSettings(): UseSettingsResult {
     setNotificationSound,
     notificationVibration,
     setNotificationVibration,
+    timerCycleEnabled,
+    setTimerCycleEnabled,
   };
 }
 
@@ -1,5 +1,7 @@
 import { useState, useEffect, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { getErrorMessage } from "@shared/lib/error";
 
 export interface SidebarCounts {
   inbox: number;
@@ -33,7 +35,7 @@ export function useSidebarCounts() {
       const result = await invoke<SidebarCounts>("get_sidebar_counts");
       setCounts(result);
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
       setCounts(defaultCounts);
     } finally {
       setLoading(false);
@@ -44,6 +46,22 @@ export function useSidebarCounts() {
     refresh();
   }, [refresh]);
 
+  // 트레이 메뉴 등 화면 밖에서 task가 변경되면 다시 조회
+  useEffect(() => {
+    const unlistenFns: (() => void)[] = [];
+    for (const event of ["tasks-changed", "db-changed"]) {
+      listen(event, () => {
+        refresh();
+      }).then((unlisten) => {
+        unlistenFns.push(unlisten);
+      });
+    }
+
+    return () => {
+      unlistenFns.forEach((unlisten) => unlisten());
+    };
+  }, [refresh]);
+
   return { counts, loading, error, refresh };
 }
 
@@ -1,5 +1,6 @@
 import { useState, useCallback, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { getErrorMessage } from "@shared/lib/error";
 
 export interface TableRow {
   columns: string[];
@@ -31,7 +32,7 @@ export function useTableViewer(): UseTableViewerResult {
       const result = await invoke<string[]>("list_tables");
       setTables(result);
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
     } finally {
       setLoading(false);
     }
@@ -44,7 +45,7 @@ export function useTableViewer(): UseTableViewerResult {
       const result = await invoke<TableRow[]>("query_table", { tableName, limit, offset });
       return result;
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
       return [];
     } finally {
       setLoading(false);
@@ -1,6 +1,8 @@
 import { useState, useCallback, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { TaskStatus, TaskPriority, Task, TaskMemo, TaskNote, TaskRunHistory, TaskActionHistory } from "@entities/task";
+import { getErrorMessage } from "@shared/lib/error";
 
 /** 백엔드에서 받아오는 Task 원시 타입 (날짜가 문자열) */
 interface TaskRaw {
@@ -24,7 +26,7 @@ interface TaskRaw {
   tags: string[];
   memos: { id: string; taskId: string; content: string; createdAt: string }[];
   notes: { id: string; taskId: string; title: string; content: string; createdAt: string; updatedAt: string }[];
-  runHistory: { id: string; taskId: string; startedAt: string; endedAt?: string; duration: number; endType: string }[];
+  runHistory: { id: string; taskId: string; startedAt: string; endedAt?: string; duration: number; endType: string; phase: string; overtimeSeconds: number }[];
   timeExtensions: { id: string; taskId: string; addedMinutes: number; previousDuration: number; newDuration: number; reason?: string; createdAt: string }[];
   actionHistory: { id: string; taskId: string; actionType: string; previousStatus?: string; newStatus?: string; metadata?: string; createdAt: string }[];
 }
@@ -95,6 +97,8 @@ function parseTask(raw: TaskRaw): Task {
       endedAt: r.endedAt ? parseUTCDateString(r.endedAt) : undefined,
       duration: r.duration,
       endType: r.endType as TaskRunHistory["endType"],
+      phase: r.phase as TaskRunHistory["phase"],
+      overtimeSeconds: r.overtimeSeconds,
     })),
     timeExtensions: raw.timeExtensions.map(t => ({
       id: t.id,
@@ -180,7 +184,7 @@ export function useTasks(): UseTasksResult {
       const result = await invoke<TaskRaw[]>("list_tasks", { status });
       setTasks(result.map(parseTask));
     } catch (e) {
-      setError(String(e));
+      setError(getErrorMessage(e));
     } finally {
       setLoading(false);
     }
@@ -274,6 +278,22 @@ export function useTasks(): UseTasksResult {
     refresh();
   }, [refresh]);
 
+  // 트레이 메뉴 등 화면 밖에서 task가 변경되거나 DB 파일이 외부에서 바뀌면 다시 조회
+  useEffect(() => {
+    const unlistenFns: (() => void)[] = [];
+    for (const event of ["tasks-changed", "db-changed"]) {
+      listen(event, () => {
+        refresh();
+      }).then((unlisten) => {
+        unlistenFns.push(unlisten);
+      });
+    }
+
+    return () => {
+      unlistenFns.forEach((unlisten) => unlisten());
+    };
+  }, [refresh]);
+
   return {
     tasks,
     loading,
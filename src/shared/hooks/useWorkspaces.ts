@@ -0,0 +1,119 @@
+import { useState, useCallback, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { getErrorMessage } from "@shared/lib/error";
+import type { DbStatus } from "./useDbStatus";
+
+export interface Workspace {
+  id: string;
+  name: string;
+  path: string;
+  /** 마지막으로 연 시각 (UTC, "YYYY-MM-DD HH:mm:ss") */
+  lastOpenedAt: string | null;
+}
+
+interface WorkspaceList {
+  activeId: string | null;
+  workspaces: Workspace[];
+}
+
+export interface UseWorkspacesResult {
+  workspaces: Workspace[];
+  activeId: string | null;
+  loading: boolean;
+  error: string | null;
+  refresh: () => Promise<void>;
+  addWorkspace: (name: string, path: string) => Promise<Workspace>;
+  renameWorkspace: (id: string, name: string) => Promise<void>;
+  removeWorkspace: (id: string) => Promise<void>;
+  switchWorkspace: (id: string) => Promise<DbStatus>;
+}
+
+export function useWorkspaces(): UseWorkspacesResult {
+  const [workspaces, setWorkspaces] = useState<Workspace[]>([]);
+  const [activeId, setActiveId] = useState<string | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const refresh = useCallback(async () => {
+    setError(null);
+    try {
+      const result = await invoke<WorkspaceList>("list_workspaces");
+      setWorkspaces(result.workspaces);
+      setActiveId(result.activeId);
+    } catch (e) {
+      setError(getErrorMessage(e));
+    }
+  }, []);
+
+  // 실행 후 목록 갱신 (실패 시 에러 저장 후 다시 던짐)
+  const run = useCallback(
+    async <T,>(action: () => Promise<T>): Promise<T> => {
+      setLoading(true);
+      setError(null);
+      try {
+        const result = await action();
+        await refresh();
+        return result;
+      } catch (e) {
+        setError(getErrorMessage(e));
+        throw e;
+      } finally {
+        setLoading(false);
+      }
+    },
+    [refresh]
+  );
+
+  const addWorkspace = useCallback(
+    (name: string, path: string) => run(() => invoke<Workspace>("add_workspace", { name, path })),
+    [run]
+  );
+
+  const renameWorkspace = useCallback(
+    async (id: string, name: string) => {
+      await run(() => invoke<Workspace>("rename_workspace", { id, name }));
+    },
+    [run]
+  );
+
+  const removeWorkspace = useCallback(
+    (id: string) => run(() => invoke<void>("remove_workspace", { id })),
+    [run]
+  );
+
+  /** 워크스페이스 전환 (암호화된 DB는 passphrase 필요, PASSPHRASE_REQUIRED 확인 후 사용) */
+  const switchWorkspace = useCallback(
+    (id: string, passphrase?: string) =>
+      run(() => invoke<DbStatus>("switch_workspace", { id, passphrase })),
+    [run]
+  );
+
+  // 트레이에서 전환한 경우에도 활성 워크스페이스 갱신
+  useEffect(() => {
+    refresh();
+
+    let unlistenFn: (() => void) | null = null;
+    listen("workspace-changed", () => {
+      refresh();
+    }).then((unlisten) => {
+      unlistenFn = unlisten;
+    });
+
+    return () => {
+      unlistenFn?.();
+    };
+  }, [refresh]);
+
+  return {
+    workspaces,
+    activeId,
+    loading,
+    error,
+    refresh,
+    addWorkspace,
+    renameWorkspace,
+    removeWorkspace,
+    switchWorkspace,
+  };
+}
@@ -0,0 +1,37 @@
+/** Rust 커맨드 에러 코드 (src-tauri/src/error.rs) */
+export type AppErrorCode =
+  | "DB_NOT_CONFIGURED"
+  | "DB_FILE_NOT_FOUND"
+  | "DB_LOCKED"
+  | "DB_LOCK_STALE"
+  | "DB_READ_ONLY"
+  | "PASSPHRASE_REQUIRED"
+  | "WRONG_PASSPHRASE"
+  | "UNSUPPORTED_SCHEMA"
+  | "UNREADABLE_SCHEMA"
+  | "NOT_FOUND"
+  | "INVALID_INPUT"
+  | "CONSTRAINT_VIOLATION"
+  | "DATABASE_ERROR"
+  | "IO_ERROR"
+  | "INTERNAL_ERROR";
+
+/** Rust 커맨드 에러 (invoke 실패 시 전달되는 값) */
+export interface AppError {
+  code: AppErrorCode;
+  /** 사용자에게 표시할 메시지 */
+  message: string;
+  /** 원본 에러 메시지 등 디버깅용 정보 */
+  details: string | null;
+}
+
+export const isAppError = (e: unknown): e is AppError =>
+  typeof e === "object" && e !== null && "code" in e && "message" in e;
+
+/** 에러 코드 (Rust 커맨드 에러가 아니면 null) */
+export const getErrorCode = (e: unknown): AppErrorCode | null =>
+  isAppError(e) ? e.code : null;
+
+/** 화면 표시용 에러 메시지 */
+export const getErrorMessage = (e: unknown): string =>
+  isAppError(e) ? e.message : String(e);
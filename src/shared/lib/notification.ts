@@ -51,29 +51,6 @@ export const sendTaskCompletedNotification = async (
   }
 };
 
-/**
- * 타이머 종료 알림 전송
- * @param taskTitle task 제목
- */
-export const sendTimerEndedNotification = async (
-  taskTitle: string
-): Promise<void> => {
-  try {
-    const permissionGranted = await isPermissionGranted();
-    if (!permissionGranted) {
-      console.warn("Notification permission not granted");
-      return;
-    }
-
-    sendNotification({
-      title: `⏰ 시간 종료!`,
-      body: `"${taskTitle}" 작업 시간이 종료되었습니다.`,
-    });
-  } catch (error) {
-    console.error("Failed to send notification:", error);
-  }
-};
-
 /**
  * 일반 알림 전송
  * @param title 알림 제목
@@ -1,26 +1,95 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { TimerPhase } from "@entities/task";
+
+/** 여러 타이머 실행 시 트레이에 표시할 타이머 선택 방식 */
+export type TrayDisplayMode = "recent" | "soonest";
+
+/** timer-ended / timer-overtime(초과 시간 측정 시작) 이벤트 페이로드 */
+export interface TimerEndedPayload {
+  taskId: string;
+}
+
+/** 타이머 모드 */
+export type TimerMode = "countdown" | "overtime" | "stopwatch";
+
+/** timer-tick 이벤트 페이로드 (Rust 타이머 엔진이 1초마다 모든 창에 전송) */
+export interface TimerTickPayload {
+  taskId: string;
+  mode: TimerMode;
+  phase: TimerPhase;
+  /** 남은 시간 (초, 스톱워치는 0) */
+  remainingSecs: number;
+  /** 스톱워치 경과 시간 (초) */
+  elapsedSecs: number;
+  /** 초과 시간 (초) */
+  overtimeSecs: number;
+  /** false면 정지된 타이머의 마지막 상태 */
+  running: boolean;
+}
+
+/** timer-phase-changed 이벤트 페이로드 (사이클 모드) */
+export interface TimerPhaseChangedPayload {
+  taskId: string;
+  phase: TimerPhase;
+  /** 완료한 집중 구간 수 */
+  focusCompleted: number;
+  /** 사이클당 집중 구간 수 */
+  intervals: number;
+  remainingSecs: number;
+}
 
 /**
  * 트레이 타이머 시작 (Play 시 호출)
- * @param remainingSecs 남은 시간 (초)
+ * @param taskId 태스크 ID
+ * @param remainingSecs 남은 시간 (초) - 사이클 모드 첫 시작 시에는 설정값 사용
  * @param taskTitle 태스크 이름
+ * @param cycle 사이클 모드 여부 (집중/휴식 구간 자동 전환, 생략하면 timer_cycle_enabled 설정 사용)
  */
-export const startTrayTimer = async (remainingSecs: number, taskTitle: string): Promise<void> => {
+export const startTrayTimer = async (taskId: string, remainingSecs: number, taskTitle: string, cycle?: boolean): Promise<void> => {
   try {
-    await invoke("start_tray_timer", { remainingSecs, taskTitle });
+    await invoke("start_tray_timer", { taskId, remainingSecs, taskTitle, cycle });
   } catch (error) {
     console.error("Failed to start tray timer:", error);
   }
 };
 
+/**
+ * 스톱워치 시작 (예상 시간이 없는 task)
+ * @param taskId 태스크 ID
+ * @param taskTitle 태스크 이름
+ * @param elapsedSecs 이어서 측정할 경과 시간 (초, 기본 0)
+ */
+export const startTrayStopwatch = async (taskId: string, taskTitle: string, elapsedSecs: number = 0): Promise<void> => {
+  try {
+    await invoke("start_tray_stopwatch", { taskId, taskTitle, elapsedSecs });
+  } catch (error) {
+    console.error("Failed to start tray stopwatch:", error);
+  }
+};
+
+/**
+ * 스톱워치 경과 시간 조회
+ * @param taskId 태스크 ID (없으면 트레이에 표시 중인 타이머)
+ * @returns [경과 시간(초), 실행 중 여부]
+ */
+export const getElapsedTime = async (taskId?: string): Promise<[number, boolean]> => {
+  try {
+    return await invoke("get_elapsed_time", { taskId }) as [number, boolean];
+  } catch (error) {
+    console.error("Failed to get elapsed time:", error);
+    return [0, false];
+  }
+};
+
 /**
  * 트레이 타이머 정지 (Pause/Stop 시 호출)
+ * @param taskId 정지할 태스크 ID (없으면 모든 타이머 정지)
  * @param updateToSlacker 실행 중인 task가 없을 때 "Slacker"로 변경할지 여부 (기본값: false)
- * @returns 남은 시간 (초)
+ * @returns 남은 시간 (초) - 스톱워치는 0 (경과 시간은 실행 기록에 저장됨)
  */
-export const stopTrayTimer = async (updateToSlacker: boolean = false): Promise<number> => {
+export const stopTrayTimer = async (taskId?: string, updateToSlacker: boolean = false): Promise<number> => {
   try {
-    return await invoke("stop_tray_timer", { updateToSlacker }) as number;
+    return await invoke("stop_tray_timer", { taskId, updateToSlacker }) as number;
   } catch (error) {
     console.error("Failed to stop tray timer:", error);
     return 0;
@@ -29,11 +98,12 @@ export const stopTrayTimer = async (updateToSlacker: boolean = false): Promise<n
 
 /**
  * 현재 남은 시간 조회 (포그라운드 복귀 시)
- * @returns [남은 시간(초), 실행 중 여부]
+ * @param taskId 태스크 ID (없으면 트레이에 표시 중인 타이머)
+ * @returns [남은 시간(초), 실행 중 여부] - 초과 시간 측정 중이면 [0, true]
  */
-export const getRemainingTime = async (): Promise<[number, boolean]> => {
+export const getRemainingTime = async (taskId?: string): Promise<[number, boolean]> => {
   try {
-    return await invoke("get_remaining_time") as [number, boolean];
+    return await invoke("get_remaining_time", { taskId }) as [number, boolean];
   } catch (error) {
     console.error("Failed to get remaining time:", error);
     return [0, false];
@@ -42,11 +112,12 @@ export const getRemainingTime = async (): Promise<[number, boolean]> => {
 
 /**
  * 트레이 타이머 시간 동기화 (시간 연장 시)
+ * @param taskId 태스크 ID (없으면 트레이에 표시 중인 타이머)
  * @param remainingSecs 새로운 남은 시간 (초)
  */
-export const syncTrayTimer = async (remainingSecs: number): Promise<void> => {
+export const syncTrayTimer = async (taskId: string | undefined, remainingSecs: number): Promise<void> => {
   try {
-    await invoke("sync_tray_timer", { remainingSecs });
+    await invoke("sync_tray_timer", { taskId, remainingSecs });
   } catch (error) {
     console.error("Failed to sync tray timer:", error);
   }
@@ -54,13 +125,26 @@ export const syncTrayTimer = async (remainingSecs: number): Promise<void> => {
 
 /**
  * 트레이 타이머 업데이트 (다른 실행 중인 task로 전환 시)
- * @param remainingSecs 남은 시간 (초)
+ * @param taskId 태스크 ID
+ * @param remainingSecs 남은 시간 (초) - 이미 실행 중인 타이머는 무시됨
  * @param taskTitle 태스크 이름
  */
-export const updateTrayTimer = async (remainingSecs: number, taskTitle: string): Promise<void> => {
+export const updateTrayTimer = async (taskId: string, remainingSecs: number, taskTitle: string): Promise<void> => {
   try {
-    await invoke("update_tray_timer", { remainingSecs, taskTitle });
+    await invoke("update_tray_timer", { taskId, remainingSecs, taskTitle });
   } catch (error) {
     console.error("Failed to update tray timer:", error);
   }
 };
+
+/**
+ * 트레이 표시 방식 변경
+ * @param mode "recent" (최근 시작) 또는 "soonest" (가장 먼저 종료)
+ */
+export const setTrayDisplayMode = async (mode: TrayDisplayMode): Promise<void> => {
+  try {
+    await invoke("set_tray_display_mode", { mode });
+  } catch (error) {
+    console.error("Failed to set tray display mode:", error);
+  }
+};
@@ -68,13 +68,14 @@ export const AppLayout = ({ children, inProgressTask, onTaskStatusChange, onAddT
 
   // Widget 모드용 타이머 훅
   const {
-    remainingTimeMs,
+    displayTimeMs,
     progress,
     isRunning,
     handlePlay,
     handlePause,
   } = useTaskTimer({
-    expectedDuration: inProgressTask?.expectedDuration ?? 5,
+    taskId: inProgressTask?.id,
+    expectedDuration: inProgressTask?.expectedDuration ?? null,
     savedRemainingTimeSeconds: inProgressTask?.remainingTimeSeconds,
     isInProgress: inProgressTask?.status === TaskStatus.IN_PROGRESS,
     taskTitle: inProgressTask?.title ?? "",
@@ -515,7 +516,7 @@ export const AppLayout = ({ children, inProgressTask, onTaskStatusChange, onAddT
                 className="font-mono text-3xl font-bold tracking-tight text-white"
                 data-tauri-drag-region
               >
-                {formatTimeMs(remainingTimeMs)}
+                {formatTimeMs(displayTimeMs)}
               </div>
 
               {/* 진행률 - 가로 프로그레스 바 */}
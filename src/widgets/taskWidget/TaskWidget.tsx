@@ -25,11 +25,12 @@ export const TaskWidget = ({ task }: TaskWidgetProps) => {
   }, []);
 
   const {
-    remainingTimeMs,
+    displayTimeMs,
     progress,
     urgencyLevel,
   } = useTaskTimer({
-    expectedDuration: task.expectedDuration ?? 5,
+    taskId: task.id,
+    expectedDuration: task.expectedDuration ?? null,
     isInProgress: task.status === TaskStatus.IN_PROGRESS,
     taskTitle: task.title,
   });
@@ -113,7 +114,7 @@ export const TaskWidget = ({ task }: TaskWidgetProps) => {
           animate={urgencyLevel === "critical" ? { scale: [1, 1.05, 1] } : {}}
           transition={{ duration: 0.5, repeat: Infinity }}
         >
-          {formatTimeMs(remainingTimeMs)}
+          {formatTimeMs(displayTimeMs)}
         </motion.div>
         
         <button
@@ -216,7 +217,7 @@ export const TaskWidget = ({ task }: TaskWidgetProps) => {
           }
           transition={{ duration: 0.5, repeat: Infinity }}
         >
-          {formatTimeMs(remainingTimeMs)}
+          {formatTimeMs(displayTimeMs)}
         </motion.div>
 
         <div className="flex-1 h-2 bg-black/30 rounded-full overflow-hidden">
@@ -232,7 +233,9 @@ export const TaskWidget = ({ task }: TaskWidgetProps) => {
       {/* 예상 시간 */}
       <div className="flex items-center gap-1 text-gray-400 text-xs">
         <Clock className="w-3 h-3" />
-        <span>예상: {formatMinutes(task.expectedDuration ?? 5)}</span>
+        <span>
+          {task.expectedDuration == null ? "예상 시간 없음" : `예상: ${formatMinutes(task.expectedDuration)}`}
+        </span>
       </div>
 
       {/* 글로우 효과 */}